@@ -0,0 +1,47 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Benchmarks of config enumeration and inspection.
+//!
+//! Requires a working EGL implementation with a default display. If the display
+//! can not be initialized, the benchmarks are skipped.
+
+#[macro_use]
+extern crate criterion;
+extern crate egli;
+
+use criterion::Criterion;
+use egli::Display;
+
+fn config_enumeration(c: &mut Criterion) {
+    let display = match Display::from_default_display() {
+        Ok(display) => display,
+        Err(e) => {
+            eprintln!("skipping config benchmarks, no EGL display: {:?}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = display.initialize() {
+        eprintln!("skipping config benchmarks, failed to initialize EGL: {:?}", e);
+        return;
+    }
+
+    let configs = display.get_configs().expect("failed to get configs");
+
+    c.bench_function("get_configs", |b| {
+        b.iter(|| display.get_configs().expect("failed to get configs"))
+    });
+
+    // Every config attribute is a separate `eglGetConfigAttrib` call.
+    c.bench_function(&format!("debug_format_{}_configs", configs.len()), |b| {
+        b.iter(|| format!("{:?}", configs))
+    });
+}
+
+criterion_group!(benches, config_enumeration);
+criterion_main!(benches);
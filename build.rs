@@ -0,0 +1,61 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Detects the EGL version of the system library and enables `cfg(egl_has_1_5)`
+//! when EGL 1.5 entry points can be linked.
+//!
+//! The explicit `egl_1_5` feature always enables EGL 1.5. Without it, the version is
+//! probed with `pkg-config egl --modversion`. That is the package version, which is
+//! only the EGL API version for libglvnd (`1.5`); the Mesa package reports its own
+//! release, such as `23.2.1`. So only a `1.x` version is trusted, and EGL 1.5 is
+//! left disabled if the probe is not possible (no `pkg-config`, cross compilation).
+
+use std::env;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+    println!("cargo:rustc-check-cfg=cfg(egl_has_1_5)");
+    println!("cargo:rustc-check-cfg=cfg(android)");
+
+    let feature_enabled = env::var_os("CARGO_FEATURE_EGL_1_5").is_some();
+
+    if feature_enabled || detect_egl_api_version().is_some_and(|v| v >= (1, 5)) {
+        println!("cargo:rustc-cfg=egl_has_1_5");
+    }
+}
+
+/// Returns `(major, minor)` EGL API version reported by `pkg-config`, if the reported
+/// package version is known to be the API version.
+fn detect_egl_api_version() -> Option<(u32, u32)> {
+    // The probe only describes the host system.
+    if env::var("TARGET").ok() != env::var("HOST").ok() {
+        return None;
+    }
+
+    let output = Command::new("pkg-config")
+        .args(["egl", "--modversion"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let version = String::from_utf8(output.stdout).ok()?;
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+
+    // Package versions of EGL implementations, such as Mesa, are not API versions.
+    if major != 1 {
+        return None;
+    }
+
+    Some((major, minor))
+}
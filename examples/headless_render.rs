@@ -0,0 +1,125 @@
+extern crate egli;
+extern crate gl;
+extern crate image;
+
+use egli::{Display, RenderableType};
+use std::env;
+use std::mem;
+use std::os::raw::c_void;
+
+const WIDTH: u32 = 640;
+const HEIGHT: u32 = 480;
+
+fn main() {
+    println!("This example requires GL, EGL installed.");
+    println!("On Ubuntu it's named `libegl1-mesa-dev`.");
+
+    let path = env::args().nth(1).unwrap_or_else(|| "headless_render.png".into());
+
+    let egl_display = Display::from_default_display().expect("Failed to get default EGL display.");
+
+    println!(
+        "Using EGL {}",
+        egl_display
+            .initialize_and_get_version()
+            .expect("failed to initialize")
+    );
+
+    let config = egl_display
+        .config_filter()
+        .with_red_size(8)
+        .with_green_size(8)
+        .with_blue_size(8)
+        .with_alpha_size(8)
+        .with_renderable_type(RenderableType::OPENGL_ES2)
+        .choose_configs_for_pbuffer(WIDTH, HEIGHT)
+        .expect("failed to get configurations")
+        .into_iter()
+        .max()
+        .expect("no compatible EGL configuration was found");
+
+    let context = egl_display
+        .context_builder(config)
+        .with_client_version(2)
+        .create()
+        .expect("failed to create OpenGL ES context");
+
+    // Without surfaces, rendering goes to a framebuffer object instead of a pbuffer.
+    let surfaceless = egl_display
+        .supports_surfaceless()
+        .expect("failed to query display");
+
+    let _pbuffer = if surfaceless {
+        println!("Rendering without surfaces.");
+        egl_display
+            .make_current_surfaceless(&context)
+            .expect("make current failed");
+        None
+    } else {
+        println!("Rendering to a pbuffer.");
+        let (surface, _, _) = egl_display
+            .pbuffer_builder(config)
+            .with_width(WIDTH)
+            .with_height(HEIGHT)
+            .create()
+            .expect("Failed to create pbuffer based surface.");
+        context
+            .make_current(&surface, &surface)
+            .expect("make current failed");
+        Some(surface)
+    };
+
+    gl::load_with(|s| unsafe { mem::transmute(egli::egl::get_proc_address(s)) });
+
+    let mut pixels = vec![0u8; (WIDTH * HEIGHT * 4) as usize];
+    unsafe {
+        let mut framebuffer = 0;
+        let mut renderbuffer = 0;
+        if surfaceless {
+            gl::GenRenderbuffers(1, &mut renderbuffer);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+            gl::RenderbufferStorage(gl::RENDERBUFFER, gl::RGBA8, WIDTH as i32, HEIGHT as i32);
+            gl::GenFramebuffers(1, &mut framebuffer);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer);
+            gl::FramebufferRenderbuffer(gl::FRAMEBUFFER,
+                                        gl::COLOR_ATTACHMENT0,
+                                        gl::RENDERBUFFER,
+                                        renderbuffer);
+            assert_eq!(gl::CheckFramebufferStatus(gl::FRAMEBUFFER),
+                       gl::FRAMEBUFFER_COMPLETE,
+                       "framebuffer is incomplete");
+        }
+
+        gl::Viewport(0, 0, WIDTH as i32, HEIGHT as i32);
+        gl::ClearColor(0.0, 0.0, 1.0, 1.0);
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+
+        // a red rectangle in the middle of the image
+        gl::Enable(gl::SCISSOR_TEST);
+        gl::Scissor((WIDTH / 4) as i32, (HEIGHT / 4) as i32, (WIDTH / 2) as i32, (HEIGHT / 2) as i32);
+        gl::ClearColor(1.0, 0.0, 0.0, 1.0);
+        gl::Clear(gl::COLOR_BUFFER_BIT);
+        gl::Disable(gl::SCISSOR_TEST);
+
+        gl::Finish();
+        gl::ReadPixels(0, 0, WIDTH as i32, HEIGHT as i32, gl::RGBA, gl::UNSIGNED_BYTE,
+                       pixels.as_mut_ptr() as *mut c_void);
+
+        if surfaceless {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            gl::DeleteFramebuffers(1, &framebuffer);
+            gl::DeleteRenderbuffers(1, &renderbuffer);
+        }
+    }
+
+    egl_display.make_not_current().expect("make not current failed");
+
+    // GL rows start at the bottom, image rows at the top
+    let row_len = (WIDTH * 4) as usize;
+    let flipped: Vec<u8> = pixels.chunks(row_len).rev().flat_map(|row| row.iter().cloned()).collect();
+
+    image::save_buffer(&path, &flipped, WIDTH, HEIGHT, image::ColorType::Rgba8)
+        .expect("failed to write the image");
+
+    println!("Saved {}x{} image to {}", WIDTH, HEIGHT, path);
+}
@@ -3,9 +3,8 @@ extern crate gl;
 extern crate libc;
 extern crate x11;
 
-use egli::egl::EGLint;
-use egli::egl::{EGL_HEIGHT, EGL_NONE, EGL_WIDTH};
-use egli::{Display, RenderableType, SurfaceType};
+use egli::egl::surface_attrib::{EGL_HEIGHT, EGL_WIDTH};
+use egli::{AttribList, Display, RenderableType};
 use std::mem;
 use std::os::raw::c_void;
 use std::{thread, time};
@@ -30,7 +29,7 @@ fn main() {
         .with_blue_size(8)
         .with_alpha_size(8)
         .with_depth_size(24)
-        .with_surface_type(SurfaceType::PBUFFER)
+        .for_pbuffer()
         .with_renderable_type(RenderableType::OPENGL_ES2)
         .choose_configs()
         .expect("failed to get configurations");
@@ -41,7 +40,9 @@ fn main() {
         .first()
         .expect("no compatible EGL configuration was found");
 
-    let pbuffer_attrs: [EGLint; 5] = [EGL_WIDTH, 640, EGL_HEIGHT, 480, EGL_NONE];
+    let mut pbuffer_attrs = AttribList::new();
+    pbuffer_attrs.push(EGL_WIDTH, 640);
+    pbuffer_attrs.push(EGL_HEIGHT, 480);
 
     let surface = egl_display
         .create_pbuffer_surface(first_config, &pbuffer_attrs)
@@ -65,8 +66,8 @@ fn main() {
             gl::Clear(gl::COLOR_BUFFER_BIT);
         }
 
-        egl_display
-            .swap_buffers(&surface)
+        surface
+            .swap_buffers()
             .expect("failed to swap buffers");
 
         // get some pixels
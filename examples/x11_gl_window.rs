@@ -48,8 +48,8 @@ fn main() {
             gl::Clear(gl::COLOR_BUFFER_BIT);
         }
 
-        egl_display.swap_buffers(&surface)
-                   .expect("failed to swap buffers");
+        surface.swap_buffers()
+               .expect("failed to swap buffers");
     });
 }
 
@@ -0,0 +1,64 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::ops::Deref;
+use egl::{self, EGLint};
+
+/// `EGLint` attribute list that is always terminated with `EGL_NONE`.
+///
+/// Dereferences to `&[EGLint]`, so it can be passed anywhere a raw attribute list
+/// is accepted, such as `Display::create_pbuffer_surface`.
+///
+/// ## Example
+///
+/// ```
+/// use egli::AttribList;
+/// use egli::egl::config_attrib::EGL_NONE;
+/// use egli::egl::surface_attrib::{EGL_HEIGHT, EGL_WIDTH};
+///
+/// let mut attribs = AttribList::new();
+/// attribs.push(EGL_WIDTH, 640);
+/// attribs.push(EGL_HEIGHT, 480);
+///
+/// assert_eq!(attribs.as_slice(), &[EGL_WIDTH, 640, EGL_HEIGHT, 480, EGL_NONE]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttribList {
+    attribs: Vec<EGLint>,
+}
+
+impl AttribList {
+    /// Create an empty list, containing only the `EGL_NONE` terminator.
+    pub fn new() -> AttribList {
+        AttribList { attribs: vec![egl::config_attrib::EGL_NONE] }
+    }
+
+    /// Append an attribute with its value before the terminator.
+    pub fn push(&mut self, key: EGLint, value: EGLint) {
+        let end = self.attribs.len() - 1;
+        self.attribs.splice(end..end, [key, value].iter().cloned());
+    }
+
+    /// Returns the list including the `EGL_NONE` terminator.
+    pub fn as_slice(&self) -> &[EGLint] {
+        &self.attribs
+    }
+}
+
+impl Default for AttribList {
+    fn default() -> AttribList {
+        AttribList::new()
+    }
+}
+
+impl Deref for AttribList {
+    type Target = [EGLint];
+
+    fn deref(&self) -> &[EGLint] {
+        self.as_slice()
+    }
+}
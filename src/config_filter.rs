@@ -7,10 +7,37 @@
 
 use std::ptr;
 use egl::{self, EGLDisplay, EGLint};
-use error::Result;
+use error::{Error, Result};
 use {FrameBufferConfigRef, ColorBufferType, ConfigCaveat, RenderableType, SurfaceType, TransparentType};
+use frame_buffer_config::returned_config_count;
+
+/// Converts a nonnegative attribute value to `EGLint`, clamping values that do not fit.
+///
+/// Panics in debug builds if the value is above `EGLint::MAX`.
+pub(crate) fn unsigned_attrib(value: u32) -> EGLint {
+    debug_assert!(value <= EGLint::MAX as u32,
+                  "attribute value {} does not fit in EGLint",
+                  value);
+    if value > EGLint::MAX as u32 {
+        EGLint::MAX
+    } else {
+        value as EGLint
+    }
+}
 
 /// `[EGL 1.0]` Configuration filter builder.
+///
+/// Only the attributes that were set are passed to `eglChooseConfig`; EGL uses its
+/// default value for the rest. For example, `with_alpha_size(0)` sends `EGL_ALPHA_SIZE`
+/// with value `0`, while never calling it leaves `EGL_ALPHA_SIZE` out of the attribute
+/// list. Use the `without_*` methods to unset a previously set attribute, so that it
+/// is not sent at all.
+///
+/// Sizes and transparent color values are nonnegative, so they are taken as `u32`.
+/// They are passed to EGL as `EGLint`, so values above `EGLint::MAX` are clamped to it,
+/// and panic in debug builds. Attributes that can be negative, such as `level`, or
+/// `EGL_DONT_CARE` for `None`, are taken as `i32` and passed as is.
+#[must_use = "the filter does nothing unless configs are chosen with it"]
 pub struct ConfigFilterRef {
     handle: EGLDisplay,
     alpha_mask_size: Option<[EGLint; 2]>,
@@ -85,7 +112,15 @@ impl ConfigFilterRef {
     ///
     /// The alpha mask buffer is used only by OpenGL and OpenGL ES client APIs.
     pub fn with_alpha_mask_size(mut self, min_size: u32) -> Self {
-        self.alpha_mask_size = Some([egl::EGL_ALPHA_MASK_SIZE, min_size as EGLint]);
+        self.alpha_mask_size = Some([egl::config_attrib::EGL_ALPHA_MASK_SIZE,
+                                     unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_alpha_mask_size`, so `EGL_ALPHA_MASK_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_alpha_mask_size(mut self) -> Self {
+        self.alpha_mask_size = None;
         self
     }
 
@@ -97,7 +132,14 @@ impl ConfigFilterRef {
     /// size are preferred.
     /// The default value is zero.
     pub fn with_alpha_size(mut self, min_size: u32) -> Self {
-        self.alpha_size = Some([egl::EGL_ALPHA_SIZE, min_size as EGLint]);
+        self.alpha_size = Some([egl::config_attrib::EGL_ALPHA_SIZE, unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_alpha_size`, so `EGL_ALPHA_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_alpha_size(mut self) -> Self {
+        self.alpha_size = None;
         self
     }
 
@@ -107,15 +149,22 @@ impl ConfigFilterRef {
     /// Currently only frame buffer configurations that support pbuffers allow this.
     /// The default value is `None`.
     pub fn with_bind_to_texture_rgb(mut self, value: Option<bool>) -> Self {
-        self.bind_to_texture_rgb = Some([egl::EGL_BIND_TO_TEXTURE_RGB,
+        self.bind_to_texture_rgb = Some([egl::config_attrib::EGL_BIND_TO_TEXTURE_RGB,
                                          match value {
-                                             None => egl::EGL_DONT_CARE,
+                                             None => egl::config_attrib::EGL_DONT_CARE,
                                              Some(true) => egl::EGL_TRUE as EGLint,
                                              Some(false) => egl::EGL_FALSE as EGLint,
                                          }]);
         self
     }
 
+    /// Clears the value set with `with_bind_to_texture_rgb`, so `EGL_BIND_TO_TEXTURE_RGB` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_bind_to_texture_rgb(mut self) -> Self {
+        self.bind_to_texture_rgb = None;
+        self
+    }
+
     /// Must be followed by one of `None`, `Some(true)`, or `Some(false)`.
     /// If `Some(true)` is specified, then only frame buffer configurations that
     /// support binding of color buffers to an OpenGL ES RGBA texture will be
@@ -123,15 +172,22 @@ impl ConfigFilterRef {
     /// Currently only frame buffer configurations that support pbuffers allow this.
     /// The default value is `None`.
     pub fn with_bind_to_texture_rgba(mut self, value: Option<bool>) -> Self {
-        self.bind_to_texture_rgba = Some([egl::EGL_BIND_TO_TEXTURE_RGBA,
+        self.bind_to_texture_rgba = Some([egl::config_attrib::EGL_BIND_TO_TEXTURE_RGBA,
                                           match value {
-                                              None => egl::EGL_DONT_CARE,
+                                              None => egl::config_attrib::EGL_DONT_CARE,
                                               Some(true) => egl::EGL_TRUE as EGLint,
                                               Some(false) => egl::EGL_FALSE as EGLint,
                                           }]);
         self
     }
 
+    /// Clears the value set with `with_bind_to_texture_rgba`, so `EGL_BIND_TO_TEXTURE_RGBA` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_bind_to_texture_rgba(mut self) -> Self {
+        self.bind_to_texture_rgba = None;
+        self
+    }
+
     /// Must be followed by a nonnegative integer that indicates the desired size
     /// of the blue component of the color buffer, in bits.
     /// If this value is zero, color buffers with the smallest blue component
@@ -139,7 +195,14 @@ impl ConfigFilterRef {
     /// of at least the specified size are preferred.
     /// The default value is zero.
     pub fn with_blue_size(mut self, min_size: u32) -> Self {
-        self.blue_size = Some([egl::EGL_BLUE_SIZE, min_size as EGLint]);
+        self.blue_size = Some([egl::config_attrib::EGL_BLUE_SIZE, unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_blue_size`, so `EGL_BLUE_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_blue_size(mut self) -> Self {
+        self.blue_size = None;
         self
     }
 
@@ -154,7 +217,14 @@ impl ConfigFilterRef {
     /// It is usually preferable to specify desired sizes for these color components
     /// individually.
     pub fn with_buffer_size(mut self, min_size: u32) -> Self {
-        self.buffer_size = Some([egl::EGL_BUFFER_SIZE, min_size as EGLint]);
+        self.buffer_size = Some([egl::config_attrib::EGL_BUFFER_SIZE, unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_buffer_size`, so `EGL_BUFFER_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_buffer_size(mut self) -> Self {
+        self.buffer_size = None;
         self
     }
 
@@ -170,14 +240,25 @@ impl ConfigFilterRef {
     ///
     /// For both RGB and luminance color buffers, EGL_ALPHA_SIZE may be zero or non-zero.
     pub fn with_color_buffer_type(mut self, value: ColorBufferType) -> Self {
-        self.color_buffer_type = Some([egl::EGL_COLOR_BUFFER_TYPE,
+        self.color_buffer_type = Some([egl::config_attrib::EGL_COLOR_BUFFER_TYPE,
                                        match value {
-                                           ColorBufferType::Rgb => egl::EGL_RGB_BUFFER,
-                                           ColorBufferType::Luminance => egl::EGL_LUMINANCE_BUFFER,
+                                           ColorBufferType::Rgb => {
+                                               egl::config_attrib::EGL_RGB_BUFFER
+                                           }
+                                           ColorBufferType::Luminance => {
+                                               egl::config_attrib::EGL_LUMINANCE_BUFFER
+                                           }
                                        }]);
         self
     }
 
+    /// Clears the value set with `with_color_buffer_type`, so `EGL_COLOR_BUFFER_TYPE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_color_buffer_type(mut self) -> Self {
+        self.color_buffer_type = None;
+        self
+    }
+
     /// Must be followed by `None`, `ConfigCaveat::None`, `ConfigCaveat::Slow`, or
     /// `ConfigCaveat::NonConformant`.
     ///
@@ -199,18 +280,27 @@ impl ConfigFilterRef {
     /// obsolete, since the same information can be specified via the EGL_CONFORMANT
     /// attribute on a per-client-API basis, not just for OpenGL ES.
     pub fn with_config_caveat(mut self, value: Option<ConfigCaveat>) -> Self {
-        self.config_caveat = Some([egl::EGL_CONFIG_CAVEAT,
+        self.config_caveat = Some([egl::config_attrib::EGL_CONFIG_CAVEAT,
                                    match value {
-                                       None => egl::EGL_DONT_CARE,
-                                       Some(ConfigCaveat::None) => egl::EGL_NONE,
-                                       Some(ConfigCaveat::Slow) => egl::EGL_SLOW_CONFIG,
+                                       None => egl::config_attrib::EGL_DONT_CARE,
+                                       Some(ConfigCaveat::None) => egl::config_attrib::EGL_NONE,
+                                       Some(ConfigCaveat::Slow) => {
+                                           egl::config_attrib::EGL_SLOW_CONFIG
+                                       }
                                        Some(ConfigCaveat::NonConformant) => {
-                                           egl::EGL_NON_CONFORMANT_CONFIG
+                                           egl::config_attrib::EGL_NON_CONFORMANT_CONFIG
                                        }
                                    }]);
         self
     }
 
+    /// Clears the value set with `with_config_caveat`, so `EGL_CONFIG_CAVEAT` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_config_caveat(mut self) -> Self {
+        self.config_caveat = None;
+        self
+    }
+
     /// Must be followed by a valid integer ID that indicates the desired EGL frame buffer
     /// configuration. When a EGL_CONFIG_ID is specified, all other attributes are ignored.
     /// The default value is `None`.
@@ -218,14 +308,21 @@ impl ConfigFilterRef {
     /// The meaning of config IDs is implementation-dependent.
     /// They are used only to uniquely identify different frame buffer configurations.
     pub fn with_config_id(mut self, value: Option<i32>) -> Self {
-        self.config_id = Some([egl::EGL_CONFIG_ID,
+        self.config_id = Some([egl::config_attrib::EGL_CONFIG_ID,
                                match value {
                                    Some(v) => v as EGLint,
-                                   None => egl::EGL_DONT_CARE,
+                                   None => egl::config_attrib::EGL_DONT_CARE,
                                }]);
         self
     }
 
+    /// Clears the value set with `with_config_id`, so `EGL_CONFIG_ID` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_config_id(mut self) -> Self {
+        self.config_id = None;
+        self
+    }
+
     /// Must be followed by a bitmask indicating which types of client API contexts
     /// created with respect to the frame buffer configuration config must pass the
     /// required conformance tests for that API. Mask bits include:
@@ -255,7 +352,14 @@ impl ConfigFilterRef {
     /// Conformance requirements limit the number of non-conformant configs that an
     /// implementation can define.
     pub fn with_conformant(mut self, value: RenderableType) -> Self {
-        self.conformant = Some([egl::EGL_CONFORMANT, value.bits() as EGLint]);
+        self.conformant = Some([egl::config_attrib::EGL_CONFORMANT, value.bits() as EGLint]);
+        self
+    }
+
+    /// Clears the value set with `with_conformant`, so `EGL_CONFORMANT` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_conformant(mut self) -> Self {
+        self.conformant = None;
         self
     }
 
@@ -266,7 +370,14 @@ impl ConfigFilterRef {
     ///
     /// The depth buffer is used only by OpenGL and OpenGL ES client APIs.
     pub fn with_depth_size(mut self, min_size: u32) -> Self {
-        self.depth_size = Some([egl::EGL_DEPTH_SIZE, min_size as EGLint]);
+        self.depth_size = Some([egl::config_attrib::EGL_DEPTH_SIZE, unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_depth_size`, so `EGL_DEPTH_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_depth_size(mut self) -> Self {
+        self.depth_size = None;
         self
     }
 
@@ -276,7 +387,14 @@ impl ConfigFilterRef {
     /// Otherwise, color buffers with the largest green component of at least the specified
     /// size are preferred. The default value is zero.
     pub fn with_green_size(mut self, min_size: u32) -> Self {
-        self.green_size = Some([egl::EGL_GREEN_SIZE, min_size as EGLint]);
+        self.green_size = Some([egl::config_attrib::EGL_GREEN_SIZE, unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_green_size`, so `EGL_GREEN_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_green_size(mut self) -> Self {
+        self.green_size = None;
         self
     }
 
@@ -289,8 +407,37 @@ impl ConfigFilterRef {
     ///
     /// Most imlementations do not support overlay or underlay planes
     /// (buffer levels other than zero).
+    ///
+    /// The level is passed to EGL unchanged, including negative values. EGL does not
+    /// accept `EGL_DONT_CARE` for this attribute; use `without_level` to leave it unset,
+    /// which selects level zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::ptr;
+    /// use egli::ConfigFilterRef;
+    /// use egli::egl::config_attrib::{EGL_LEVEL, EGL_NONE};
+    ///
+    /// let filter = || ConfigFilterRef::from_native(ptr::null_mut());
+    ///
+    /// assert_eq!(filter().with_level(-1).attrib_list(), vec![EGL_LEVEL, -1, EGL_NONE]);
+    /// assert_eq!(filter().with_level(0).attrib_list(), vec![EGL_LEVEL, 0, EGL_NONE]);
+    /// assert_eq!(filter().with_level(2).attrib_list(), vec![EGL_LEVEL, 2, EGL_NONE]);
+    /// assert_eq!(filter().with_level(i32::MIN).attrib_list(),
+    ///            vec![EGL_LEVEL, i32::MIN, EGL_NONE]);
+    /// assert_eq!(filter().with_level(2).without_level().attrib_list(), vec![EGL_NONE]);
+    /// assert_eq!(filter().attrib_list(), vec![EGL_NONE]);
+    /// ```
     pub fn with_level(mut self, level: i32) -> Self {
-        self.level = Some([egl::EGL_LEVEL, level as EGLint]);
+        self.level = Some([egl::config_attrib::EGL_LEVEL, level as EGLint]);
+        self
+    }
+
+    /// Clears the value set with `with_level`, so `EGL_LEVEL` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_level(mut self) -> Self {
+        self.level = None;
         self
     }
 
@@ -301,7 +448,15 @@ impl ConfigFilterRef {
     /// luminance component of at least the specified size are preferred.
     /// The default value is zero.
     pub fn with_luminance_size(mut self, level: u32) -> Self {
-        self.luminance_size = Some([egl::EGL_LUMINANCE_SIZE, level as EGLint]);
+        self.luminance_size = Some([egl::config_attrib::EGL_LUMINANCE_SIZE,
+                                    unsigned_attrib(level)]);
+        self
+    }
+
+    /// Clears the value set with `with_luminance_size`, so `EGL_LUMINANCE_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_luminance_size(mut self) -> Self {
+        self.luminance_size = None;
         self
     }
 
@@ -314,56 +469,91 @@ impl ConfigFilterRef {
     /// EGL_MATCH_NATIVE_PIXMAP was introduced due to the difficulty of determining an EGLConfig
     /// compatibile with a native pixmap using only color component sizes.
     pub fn with_match_native_pixmap(mut self, handle: Option<i32>) -> Self {
-        self.match_native_pixmap = Some([egl::EGL_MATCH_NATIVE_PIXMAP,
+        self.match_native_pixmap = Some([egl::config_attrib::EGL_MATCH_NATIVE_PIXMAP,
                                          match handle {
                                              Some(v) => v as EGLint,
-                                             None => egl::EGL_NONE,
+                                             None => egl::config_attrib::EGL_NONE,
                                          }]);
         self
     }
 
+    /// Clears the value set with `with_match_native_pixmap`, so `EGL_MATCH_NATIVE_PIXMAP` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_match_native_pixmap(mut self) -> Self {
+        self.match_native_pixmap = None;
+        self
+    }
+
     /// Must be followed by EGL_DONT_CARE, EGL_TRUE, or EGL_FALSE. If EGL_TRUE is specified,
     /// then only frame buffer configurations that allow native rendering into the surface
     /// will be considered. The default value is EGL_DONT_CARE.
     pub fn with_native_renderable(mut self, value: Option<bool>) -> Self {
-        self.native_renderable = Some([egl::EGL_NATIVE_RENDERABLE,
+        self.native_renderable = Some([egl::config_attrib::EGL_NATIVE_RENDERABLE,
                                        match value {
                                            Some(true) => egl::EGL_TRUE as EGLint,
                                            Some(false) => egl::EGL_FALSE as EGLint,
-                                           None => egl::EGL_DONT_CARE,
+                                           None => egl::config_attrib::EGL_DONT_CARE,
                                        }]);
         self
     }
 
+    /// Clears the value set with `with_native_renderable`, so `EGL_NATIVE_RENDERABLE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_native_renderable(mut self) -> Self {
+        self.native_renderable = None;
+        self
+    }
+
     /// Must be followed by a integer that indicates the maximum value that can be passed to
     /// `eglSwapInterval`. The default value is `None`.
     pub fn with_max_swap_interval(mut self, value: Option<i32>) -> Self {
-        self.max_swap_interval = Some([egl::EGL_MAX_SWAP_INTERVAL,
+        self.max_swap_interval = Some([egl::config_attrib::EGL_MAX_SWAP_INTERVAL,
                                        match value {
                                            Some(value) => value as EGLint,
-                                           None => egl::EGL_DONT_CARE,
+                                           None => egl::config_attrib::EGL_DONT_CARE,
                                        }]);
         self
     }
 
+    /// Clears the value set with `with_max_swap_interval`, so `EGL_MAX_SWAP_INTERVAL` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_max_swap_interval(mut self) -> Self {
+        self.max_swap_interval = None;
+        self
+    }
+
     /// Must be followed by a integer that indicates the minimum value that can be passed to
     /// `eglSwapInterval`. The default value is `None`.
     pub fn with_min_swap_interval(mut self, value: Option<i32>) -> Self {
-        self.min_swap_interval = Some([egl::EGL_MIN_SWAP_INTERVAL,
+        self.min_swap_interval = Some([egl::config_attrib::EGL_MIN_SWAP_INTERVAL,
                                        match value {
                                            Some(value) => value as EGLint,
-                                           None => egl::EGL_DONT_CARE,
+                                           None => egl::config_attrib::EGL_DONT_CARE,
                                        }]);
         self
     }
 
+    /// Clears the value set with `with_min_swap_interval`, so `EGL_MIN_SWAP_INTERVAL` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_min_swap_interval(mut self) -> Self {
+        self.min_swap_interval = None;
+        self
+    }
+
     /// Must be followed by a nonnegative integer that indicates the desired size of the red
     /// component of the color buffer, in bits. If this value is zero, color buffers with the
     /// smallest red component size are preferred. Otherwise, color buffers with the largest
     /// red component of at least the specified size are preferred.
     /// The default value is zero.
     pub fn with_red_size(mut self, min_size: u32) -> Self {
-        self.red_size = Some([egl::EGL_RED_SIZE, min_size as EGLint]);
+        self.red_size = Some([egl::config_attrib::EGL_RED_SIZE, unsigned_attrib(min_size)]);
+        self
+    }
+
+    /// Clears the value set with `with_red_size`, so `EGL_RED_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_red_size(mut self) -> Self {
+        self.red_size = None;
         self
     }
 
@@ -373,7 +563,14 @@ impl ConfigFilterRef {
     /// buffer is undefined, so only values of zero or one will produce a match.
     /// The default value is zero.
     pub fn with_sample_buffers(mut self, value: i32) -> Self {
-        self.sample_buffers = Some([egl::EGL_SAMPLE_BUFFERS, value as EGLint]);
+        self.sample_buffers = Some([egl::config_attrib::EGL_SAMPLE_BUFFERS, value as EGLint]);
+        self
+    }
+
+    /// Clears the value set with `with_sample_buffers`, so `EGL_SAMPLE_BUFFERS` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_sample_buffers(mut self) -> Self {
+        self.sample_buffers = None;
         self
     }
 
@@ -384,7 +581,14 @@ impl ConfigFilterRef {
     /// However, multisampled colors maintain at least as much color resolution in aggregate
     /// as the main color buffers.
     pub fn with_samples(mut self, value: i32) -> Self {
-        self.samples = Some([egl::EGL_SAMPLES, value as EGLint]);
+        self.samples = Some([egl::config_attrib::EGL_SAMPLES, value as EGLint]);
+        self
+    }
+
+    /// Clears the value set with `with_samples`, so `EGL_SAMPLES` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_samples(mut self) -> Self {
+        self.samples = None;
         self
     }
 
@@ -395,7 +599,14 @@ impl ConfigFilterRef {
     ///
     /// The stencil buffer is used only by OpenGL and OpenGL ES client APIs.
     pub fn with_stencil_size(mut self, value: u32) -> Self {
-        self.stencil_size = Some([egl::EGL_STENCIL_SIZE, value as EGLint]);
+        self.stencil_size = Some([egl::config_attrib::EGL_STENCIL_SIZE, unsigned_attrib(value)]);
+        self
+    }
+
+    /// Clears the value set with `with_stencil_size`, so `EGL_STENCIL_SIZE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_stencil_size(mut self) -> Self {
+        self.stencil_size = None;
         self
     }
 
@@ -404,7 +615,15 @@ impl ConfigFilterRef {
     /// Mask bits are the same as for attribute EGL_CONFORMANT.
     /// The default value is EGL_OPENGL_ES_BIT.
     pub fn with_renderable_type(mut self, value: RenderableType) -> Self {
-        self.renderable_type = Some([egl::EGL_RENDERABLE_TYPE, value.bits() as EGLint]);
+        self.renderable_type = Some([egl::config_attrib::EGL_RENDERABLE_TYPE,
+                                     value.bits() as EGLint]);
+        self
+    }
+
+    /// Clears the value set with `with_renderable_type`, so `EGL_RENDERABLE_TYPE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_renderable_type(mut self) -> Self {
+        self.renderable_type = None;
         self
     }
 
@@ -447,8 +666,37 @@ impl ConfigFilterRef {
     /// For example, if the bitmask is set to `SurfaceType::WINDOW` | `SurfaceType::PIXMAP`, only
     /// frame buffer configurations that support both windows and pixmaps will be considered.
     /// The default value is `SurfaceType::WINDOW`.
+    ///
+    /// Because of this default, a filter that does not set the surface type only
+    /// finds configs that support windows, and may find none for pbuffer use. Use
+    /// `for_pbuffer` or `for_window` to make the intent explicit.
     pub fn with_surface_type(mut self, value: SurfaceType) -> Self {
-        self.surface_type = Some([egl::EGL_SURFACE_TYPE, value.bits() as EGLint]);
+        self.surface_type = Some([egl::config_attrib::EGL_SURFACE_TYPE, value.bits() as EGLint]);
+        self
+    }
+
+    /// Only consider configs that support pbuffer surfaces, for
+    /// `Display::create_pbuffer_surface`.
+    ///
+    /// Shortcut for `with_surface_type(SurfaceType::PBUFFER)`, replacing the default
+    /// `SurfaceType::WINDOW` requirement.
+    pub fn for_pbuffer(self) -> Self {
+        self.with_surface_type(SurfaceType::PBUFFER)
+    }
+
+    /// Only consider configs that support window surfaces, for
+    /// `Display::create_window_surface`.
+    ///
+    /// Shortcut for `with_surface_type(SurfaceType::WINDOW)`, which is also the EGL
+    /// default.
+    pub fn for_window(self) -> Self {
+        self.with_surface_type(SurfaceType::WINDOW)
+    }
+
+    /// Clears the value set with `with_surface_type`, so `EGL_SURFACE_TYPE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_surface_type(mut self) -> Self {
+        self.surface_type = None;
         self
     }
 
@@ -460,16 +708,23 @@ impl ConfigFilterRef {
     ///
     /// Most implementations support only opaque frame buffer configurations.
     pub fn with_transparent_type(mut self, value: TransparentType) -> Self {
-        self.transparent_type = Some([egl::EGL_TRANSPARENT_TYPE,
+        self.transparent_type = Some([egl::config_attrib::EGL_TRANSPARENT_TYPE,
                                       match value {
-                                          TransparentType::None => egl::EGL_NONE,
+                                          TransparentType::None => egl::config_attrib::EGL_NONE,
                                           TransparentType::TransparentRgb => {
-                                              egl::EGL_TRANSPARENT_RGB
+                                              egl::config_attrib::EGL_TRANSPARENT_RGB
                                           }
                                       }]);
         self
     }
 
+    /// Clears the value set with `with_transparent_type`, so `EGL_TRANSPARENT_TYPE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_transparent_type(mut self) -> Self {
+        self.transparent_type = None;
+        self
+    }
+
     /// Must be followed by an integer value indicating the transparent red value.
     /// The value must be between zero and the maximum color buffer value for red.
     /// Only frame buffer configurations that use the specified transparent red value
@@ -478,14 +733,21 @@ impl ConfigFilterRef {
     /// This attribute is ignored unless `EGL_TRANSPARENT_TYPE` is included in attrib_list
     /// and specified as `TransparentType::TransparentRgb`.
     pub fn with_transparent_red_value(mut self, value: Option<u32>) -> Self {
-        self.transparent_red_value = Some([egl::EGL_TRANSPARENT_RED_VALUE,
+        self.transparent_red_value = Some([egl::config_attrib::EGL_TRANSPARENT_RED_VALUE,
                                            match value {
-                                               None => egl::EGL_DONT_CARE,
-                                               Some(value) => value as EGLint,
+                                               None => egl::config_attrib::EGL_DONT_CARE,
+                                               Some(value) => unsigned_attrib(value),
                                            }]);
         self
     }
 
+    /// Clears the value set with `with_transparent_red_value`, so `EGL_TRANSPARENT_RED_VALUE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_transparent_red_value(mut self) -> Self {
+        self.transparent_red_value = None;
+        self
+    }
+
     /// Must be followed by an integer value indicating the transparent green value.
     /// The value must be between zero and the maximum color buffer value for green.
     /// Only frame buffer configurations that use the specified transparent green value
@@ -494,14 +756,21 @@ impl ConfigFilterRef {
     /// This attribute is ignored unless `EGL_TRANSPARENT_TYPE` is included in attrib_list
     /// and specified as `TransparentType::TransparentRgb`.
     pub fn with_transparent_green_value(mut self, value: Option<u32>) -> Self {
-        self.transparent_green_value = Some([egl::EGL_TRANSPARENT_GREEN_VALUE,
+        self.transparent_green_value = Some([egl::config_attrib::EGL_TRANSPARENT_GREEN_VALUE,
                                              match value {
-                                                 None => egl::EGL_DONT_CARE,
-                                                 Some(value) => value as EGLint,
+                                                 None => egl::config_attrib::EGL_DONT_CARE,
+                                                 Some(value) => unsigned_attrib(value),
                                              }]);
         self
     }
 
+    /// Clears the value set with `with_transparent_green_value`, so `EGL_TRANSPARENT_GREEN_VALUE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_transparent_green_value(mut self) -> Self {
+        self.transparent_green_value = None;
+        self
+    }
+
     /// Must be followed by an integer value indicating the transparent blue value.
     /// The value must be between zero and the maximum color buffer value for blue.
     /// Only frame buffer configurations that use the specified transparent blue value
@@ -510,65 +779,166 @@ impl ConfigFilterRef {
     /// This attribute is ignored unless `EGL_TRANSPARENT_TYPE` is included in attrib_list
     /// and specified as `TransparentType::TransparentRgb`.
     pub fn with_transparent_blue_value(mut self, value: Option<u32>) -> Self {
-        self.transparent_blue_value = Some([egl::EGL_TRANSPARENT_BLUE_VALUE,
+        self.transparent_blue_value = Some([egl::config_attrib::EGL_TRANSPARENT_BLUE_VALUE,
                                             match value {
-                                                None => egl::EGL_DONT_CARE,
-                                                Some(value) => value as EGLint,
+                                                None => egl::config_attrib::EGL_DONT_CARE,
+                                                Some(value) => unsigned_attrib(value),
                                             }]);
         self
     }
 
+    /// Clears the value set with `with_transparent_blue_value`, so `EGL_TRANSPARENT_BLUE_VALUE` is not passed to
+    /// `eglChooseConfig` and the EGL default is used.
+    pub fn without_transparent_blue_value(mut self) -> Self {
+        self.transparent_blue_value = None;
+        self
+    }
+
     /// Get filtered display configurations.
     ///
     /// Internally, this calls `eglChooseConfig` twice: to get total filtered config count,
     /// and to fill the allocated memory with config handles.
+    /// The attribute list is built once and passed to both calls.
     ///
     /// These handles are then wrapped into a new `Vec<FrameBufferConfigRef>`.
+    #[must_use = "choosing configs has no effect other than returning them"]
     pub fn choose_configs(self) -> Result<Vec<FrameBufferConfigRef>> {
-        let attrib_list: Vec<_> = [self.alpha_mask_size,
-                                   self.alpha_size,
-                                   self.bind_to_texture_rgb,
-                                   self.bind_to_texture_rgba,
-                                   self.blue_size,
-                                   self.buffer_size,
-                                   self.color_buffer_type,
-                                   self.config_caveat,
-                                   self.config_id,
-                                   self.conformant,
-                                   self.depth_size,
-                                   self.green_size,
-                                   self.level,
-                                   self.luminance_size,
-                                   self.match_native_pixmap,
-                                   self.native_renderable,
-                                   self.max_swap_interval,
-                                   self.min_swap_interval,
-                                   self.red_size,
-                                   self.sample_buffers,
-                                   self.samples,
-                                   self.stencil_size,
-                                   self.renderable_type,
-                                   self.surface_type,
-                                   self.transparent_type,
-                                   self.transparent_red_value,
-                                   self.transparent_green_value,
-                                   self.transparent_blue_value]
-                                      .iter()
-                                      .flat_map(|option| option)
-                                      .flat_map(|arr| arr)
-                                      .chain(&[egl::EGL_NONE])
-                                      .cloned()
-                                      .collect();
-
-        let count = egl::num_filtered_configs(self.handle, &attrib_list)? as usize;
+        let attrib_list = self.attrib_list();
+
+        let count = egl::num_filtered_configs(self.handle, &attrib_list)
+                        .map_err(Error::from_failed_call)?;
 
         let mut configs: Vec<egl::EGLConfig> = vec![ptr::null_mut(); count];
         let returned_count =
-            egl::get_filtered_configs(self.handle, &attrib_list, &mut configs)? as usize;
+            egl::get_filtered_configs(self.handle, &attrib_list, &mut configs)
+                .map_err(Error::from_failed_call)?;
+        let returned_count = returned_config_count(returned_count, configs.len())?;
 
         Ok(configs[..returned_count]
                .iter()
                .map(|c| FrameBufferConfigRef::from_native(self.handle, *c))
                .collect())
     }
+
+    /// Get filtered display configurations as an iterator.
+    ///
+    /// Like `choose_configs`, this fetches all matching config handles up front, since
+    /// EGL has no way to enumerate them one at a time, but wraps each handle into a
+    /// `FrameBufferConfigRef` only when the iterator reaches it. Handy with `find`
+    /// or `next` when only one config is needed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use egli::{Display, SurfaceType};
+    ///
+    /// let display = Display::from_default_display()
+    ///                      .expect("failed to get default display");
+    /// display.initialize().expect("failed to initialize");
+    /// let config = display.config_filter()
+    ///                     .with_surface_type(SurfaceType::PBUFFER)
+    ///                     .choose_configs_iter()
+    ///                     .expect("failed to get configs")
+    ///                     .find(|c| c.depth_size().unwrap_or(0) >= 24);
+    /// ```
+    #[must_use = "choosing configs has no effect other than returning them"]
+    pub fn choose_configs_iter(self) -> Result<impl Iterator<Item = FrameBufferConfigRef>> {
+        let attrib_list = self.attrib_list();
+
+        let count = egl::num_filtered_configs(self.handle, &attrib_list)
+                        .map_err(Error::from_failed_call)?;
+
+        let mut configs: Vec<egl::EGLConfig> = vec![ptr::null_mut(); count];
+        let returned_count =
+            egl::get_filtered_configs(self.handle, &attrib_list, &mut configs)
+                .map_err(Error::from_failed_call)?;
+        let returned_count = returned_config_count(returned_count, configs.len())?;
+        configs.truncate(returned_count);
+
+        let handle = self.handle;
+        Ok(configs.into_iter().map(move |c| FrameBufferConfigRef::from_native(handle, c)))
+    }
+
+    /// Get at most `max` filtered display configurations.
+    ///
+    /// Unlike `choose_configs`, this calls `eglChooseConfig` only once, with a buffer
+    /// of `max` config handles. EGL returns configs sorted by its selection priority,
+    /// so the result contains the best `max` matches.
+    #[must_use = "choosing configs has no effect other than returning them"]
+    pub fn choose_configs_limited(self, max: usize) -> Result<Vec<FrameBufferConfigRef>> {
+        let attrib_list = self.attrib_list();
+
+        let mut configs: Vec<egl::EGLConfig> = vec![ptr::null_mut(); max];
+        let returned_count =
+            egl::get_filtered_configs(self.handle, &attrib_list, &mut configs)
+                .map_err(Error::from_failed_call)?;
+        let returned_count = returned_config_count(returned_count, configs.len())?;
+
+        Ok(configs[..returned_count]
+               .iter()
+               .map(|c| FrameBufferConfigRef::from_native(self.handle, *c))
+               .collect())
+    }
+
+    /// Get filtered display configurations that can hold a pbuffer of the given size.
+    ///
+    /// Sets the surface type to `SurfaceType::PBUFFER` like `for_pbuffer`, then keeps
+    /// only the configs whose `max_pbuffer_width_u32`, `max_pbuffer_height_u32` and
+    /// `max_pbuffer_pixels_u32` fit `width` x `height`. EGL can not filter on these itself,
+    /// so they are queried for each matching config.
+    #[must_use = "choosing configs has no effect other than returning them"]
+    pub fn choose_configs_for_pbuffer(self,
+                                      width: u32,
+                                      height: u32)
+                                      -> Result<Vec<FrameBufferConfigRef>> {
+        let mut matching = Vec::new();
+        for config in self.for_pbuffer().choose_configs()? {
+            if config.max_pbuffer_width_u32()? >= width &&
+               config.max_pbuffer_height_u32()? >= height &&
+               u64::from(config.max_pbuffer_pixels_u32()?) >= u64::from(width) * u64::from(height) {
+                matching.push(config);
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Returns the `EGL_NONE` terminated attribute list passed to `eglChooseConfig`.
+    ///
+    /// Contains only the attributes that were set.
+    pub fn attrib_list(&self) -> Vec<EGLint> {
+        let attribs = [self.alpha_mask_size,
+                       self.alpha_size,
+                       self.bind_to_texture_rgb,
+                       self.bind_to_texture_rgba,
+                       self.blue_size,
+                       self.buffer_size,
+                       self.color_buffer_type,
+                       self.config_caveat,
+                       self.config_id,
+                       self.conformant,
+                       self.depth_size,
+                       self.green_size,
+                       self.level,
+                       self.luminance_size,
+                       self.match_native_pixmap,
+                       self.native_renderable,
+                       self.max_swap_interval,
+                       self.min_swap_interval,
+                       self.red_size,
+                       self.sample_buffers,
+                       self.samples,
+                       self.stencil_size,
+                       self.renderable_type,
+                       self.surface_type,
+                       self.transparent_type,
+                       self.transparent_red_value,
+                       self.transparent_green_value,
+                       self.transparent_blue_value];
+
+        // Flattening loses the size hint, so reserve space for all attributes up front.
+        let mut attrib_list = Vec::with_capacity(attribs.len() * 2 + 1);
+        attrib_list.extend(attribs.iter().flatten().flatten());
+        attrib_list.push(egl::config_attrib::EGL_NONE);
+        attrib_list
+    }
 }
@@ -5,7 +5,12 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use std::convert::TryFrom;
+use std::rc::Rc;
 use egl;
+use trace;
+use error::{Error, Result};
+use {ContextPriority, RenderBuffer, Surface};
 
 /// `[EGL 1.0]` [RAII](https://en.wikipedia.org/wiki/Resource_Acquisition_Is_Initialization) wrapper for
 /// EGLContext.
@@ -14,15 +19,27 @@ use egl;
 ///
 /// Note that the surface would not be immediately freed if it is current to any thread.
 /// In such a case, the surface will be freed when it is no longer used.
+#[must_use = "dropping a `Context` destroys it immediately"]
 pub struct Context {
     terminated: bool,
     display_handle: egl::EGLDisplay,
     handle: egl::EGLContext,
+    display_token: Option<Rc<()>>,
+    share_token: Option<Rc<()>>,
+    sharing_children: Rc<()>,
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
         if !self.terminated {
+            let alive = Rc::strong_count(&self.sharing_children) - 1;
+            if cfg!(debug_assertions) && alive > 0 {
+                eprintln!("egli: Context dropped while {} context(s) sharing data with it \
+                           are still alive; the shared data may become invalid",
+                          alive);
+            }
+
+            trace::trace("Context", "destroyed", self.handle);
             let _ = egl::destroy_context(self.display_handle, self.handle);
         }
     }
@@ -30,34 +47,180 @@ impl Drop for Context {
 
 impl Into<egl::EGLContext> for Context {
     fn into(self) -> egl::EGLContext {
-        self.forget()
+        self.into_raw()
     }
 }
 
 impl Context {
     /// Create a `Context` from an existing EGL display and context handles.
-    pub fn from_handle(display_handle: egl::EGLDisplay,
-                       context_handle: egl::EGLSurface)
-                       -> Context {
+    ///
+    /// The created `Context` takes ownership of the handle and destroys it when dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::Context;
+    /// use egli::egl::{EGLContext, EGLDisplay};
+    ///
+    /// let display_handle = 1 as EGLDisplay;
+    /// let context_handle = 2 as EGLContext;
+    ///
+    /// let context = Context::from_raw(display_handle, context_handle);
+    /// assert_eq!(context.into_raw(), context_handle);
+    /// ```
+    pub fn from_raw(display_handle: egl::EGLDisplay,
+                    context_handle: egl::EGLContext)
+                    -> Context {
+        trace::trace("Context", "created", context_handle);
         Context {
             terminated: false,
             display_handle: display_handle,
             handle: context_handle,
+            display_token: None,
+            share_token: None,
+            sharing_children: Rc::new(()),
         }
     }
 
+    /// Create a `Context` from an existing EGL display and context handles.
+    #[deprecated(note = "use `Context::from_raw`")]
+    pub fn from_handle(display_handle: egl::EGLDisplay,
+                       context_handle: egl::EGLSurface)
+                       -> Context {
+        Context::from_raw(display_handle, context_handle)
+    }
+
+    /// Keeps a token of the `Display` that created this object, so the display
+    /// can detect that it is dropped too early.
+    pub(crate) fn with_display_token(mut self, token: Rc<()>) -> Context {
+        self.display_token = Some(token);
+        self
+    }
+
+    /// Marks this context as created with a share context, keeping a token of it,
+    /// so the share context can detect that it is dropped too early.
+    pub(crate) fn with_share_token(mut self, token: Option<Rc<()>>) -> Context {
+        self.share_token = token;
+        self
+    }
+
+    /// Token handed to contexts created with this one as their share context.
+    pub(crate) fn sharing_token(&self) -> Rc<()> {
+        self.sharing_children.clone()
+    }
+
+    /// Returns `true` if this context was created sharing data with another context,
+    /// with `Display::create_shared_context` or `ContextBuilder::with_share_context`.
+    ///
+    /// EGL can not report this, so a `Context` created with `from_raw` always
+    /// returns `false`.
+    pub fn is_shared(&self) -> bool {
+        self.share_token.is_some()
+    }
+
     /// Get raw handle.
     pub fn handle(&self) -> egl::EGLContext {
         self.handle
     }
 
+    /// Get raw handle of the display this context was created on.
+    pub fn display_handle(&self) -> egl::EGLDisplay {
+        self.display_handle
+    }
+
+    /// `[EGL 1.0]` Attach this rendering context to EGL surfaces.
+    ///
+    /// Same as `Display::make_current`, but uses the display this context was
+    /// created on, so the `Display` does not need to be passed around.
+    ///
+    /// Returns `Error::MismatchedDisplay` if any of the surfaces was created on
+    /// a different display.
+    pub fn make_current(&self, draw: &Surface, read: &Surface) -> Result<()> {
+        if draw.display_handle() != self.display_handle ||
+           read.display_handle() != self.display_handle {
+            return Err(Error::MismatchedDisplay);
+        }
+        egl::make_current(self.display_handle, draw.handle(), read.handle(), self.handle)?;
+        Ok(())
+    }
+
+    /// `[EGL 1.0]` Returns the value of a context attribute.
+    ///
+    /// Escape hatch for attributes without a typed accessor, including vendor and
+    /// extension ones. The typed accessors use it too.
+    ///
+    /// Result of `eglQueryContext` with the `attribute` parameter.
+    pub fn query_attrib(&self, attribute: egl::EGLint) -> Result<i32> {
+        Ok(egl::query_context_value(self.display_handle, self.handle, attribute)?)
+    }
+
+    /// `[EGL 1.2]` Returns which buffer this context renders to.
+    ///
+    /// Unlike `Surface::render_buffer`, which reports the buffer requested when the
+    /// surface was created, this reports the buffer the context actually renders to.
+    /// They can differ, for example if the requested single buffer rendering is not
+    /// available for a window.
+    ///
+    /// EGL only reports it while the context is bound to a surface, so this fails with
+    /// `Error::ContextNotCurrent` unless the context is current to the calling thread
+    /// with `draw_surface` as its draw surface.
+    ///
+    /// Result of `eglQueryContext` with `EGL_RENDER_BUFFER` parameter.
+    pub fn render_buffer(&self, draw_surface: &Surface) -> Result<RenderBuffer> {
+        if draw_surface.display_handle() != self.display_handle {
+            return Err(Error::MismatchedDisplay);
+        }
+        if egl::get_current_context().ok() != Some(self.handle) ||
+           egl::get_current_surface(egl::EGL_DRAW).ok() != Some(draw_surface.handle()) {
+            return Err(Error::ContextNotCurrent);
+        }
+
+        let value = self.query_attrib(egl::surface_attrib::EGL_RENDER_BUFFER)?;
+        RenderBuffer::try_from(value)
+    }
+
+    /// `[EGL_IMG_context_priority]` Returns the scheduling priority granted to this
+    /// context, which may be lower than requested with `ContextBuilder::with_priority`.
+    ///
+    /// Result of `eglQueryContext` with `EGL_CONTEXT_PRIORITY_LEVEL_IMG` parameter.
+    pub fn priority(&self) -> Result<ContextPriority> {
+        let value = self.query_attrib(egl::context_attrib::EGL_CONTEXT_PRIORITY_LEVEL_IMG)?;
+        ContextPriority::try_from(value)
+    }
+
+    /// Disables cleanup of this `Context` and leaks it, returning a `'static` reference.
+    ///
+    /// Unlike `into_raw`, the wrapper stays usable, for when C code takes ownership of
+    /// the handle but Rust code keeps calling methods on it. The `Context` is never
+    /// dropped, and `eglDestroyContext` is never called for it; the C side is responsible
+    /// for that. Methods called after the C side destroys the context will fail or,
+    /// if EGL reuses the handle, affect an unrelated context.
+    ///
+    /// The leaked `Context` no longer counts towards the `Display` drop check.
+    ///
+    /// The memory of the wrapper itself is leaked, so use this only for
+    /// long-lived contexts.
+    pub fn leak(mut self) -> &'static Context {
+        self.terminated = true;
+        self.display_token = None;
+        Box::leak(Box::new(self))
+    }
+
     /// Drops `Context` without cleaning up any resources.
     ///
     /// Returns `EGLContext` handle.
     ///
     /// Alias for `Into<egl::EGLContext>`.
-    pub fn forget(mut self) -> egl::EGLContext {
+    pub fn into_raw(mut self) -> egl::EGLContext {
         self.terminated = true;
         self.handle
     }
+
+    /// Drops `Context` without cleaning up any resources.
+    ///
+    /// Returns `EGLContext` handle.
+    #[deprecated(note = "use `Context::into_raw`")]
+    pub fn forget(self) -> egl::EGLContext {
+        self.into_raw()
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::ptr;
+use std::rc::Rc;
+use egl::{self, EGLContext, EGLDisplay, EGLint};
+use error::{Error, Result};
+use {Context, ContextPriority, FrameBufferConfigRef};
+use config_filter::unsigned_attrib;
+
+/// `[EGL 1.0]` Rendering context builder.
+///
+/// Created with `Display::context_builder`. Collects the attributes passed to
+/// `eglCreateContext`; attributes that are not set are not sent to EGL.
+#[must_use = "the builder does nothing unless `create` is called"]
+pub struct ContextBuilder {
+    handle: EGLDisplay,
+    display_token: Rc<()>,
+    initialized: bool,
+    has_priority_ext: bool,
+    config: FrameBufferConfigRef,
+    share_context: Option<(EGLDisplay, EGLContext, Rc<()>)>,
+    client_version: Option<[EGLint; 2]>,
+    debug: Option<[EGLint; 2]>,
+    priority: Option<[EGLint; 2]>,
+}
+
+impl ContextBuilder {
+    pub(crate) fn new(handle: EGLDisplay,
+                      display_token: Rc<()>,
+                      initialized: bool,
+                      has_priority_ext: bool,
+                      config: FrameBufferConfigRef)
+                      -> ContextBuilder {
+        ContextBuilder {
+            handle,
+            display_token,
+            initialized,
+            has_priority_ext,
+            config,
+            share_context: None,
+            client_version: None,
+            debug: None,
+            priority: None,
+        }
+    }
+
+    /// `[EGL 1.0]` Share data, such as textures and buffers, with `context`.
+    ///
+    /// `context` must still be alive when `create` is called. If it belongs to another
+    /// display, `create` fails with `Error::MismatchedDisplay`.
+    ///
+    /// Some drivers invalidate the shared data when `context` is destroyed, so keep it
+    /// alive for as long as the created context. In debug builds, dropping `context`
+    /// while the created context is still alive prints a warning.
+    pub fn with_share_context(mut self, context: &Context) -> Self {
+        self.share_context = Some((context.display_handle(),
+                                   context.handle(),
+                                   context.sharing_token()));
+        self
+    }
+
+    /// `[EGL 1.3]` Must be followed by the major version of the OpenGL ES context
+    /// to create. The default value is 1.
+    ///
+    /// Sets `EGL_CONTEXT_CLIENT_VERSION` attribute.
+    pub fn with_client_version(mut self, major: u32) -> Self {
+        self.client_version = Some([egl::context_attrib::EGL_CONTEXT_CLIENT_VERSION,
+                                    unsigned_attrib(major)]);
+        self
+    }
+
+    /// `[EGL 1.5]` If `true`, an OpenGL debug context is requested. The default value
+    /// is `false`.
+    ///
+    /// A debug context only makes debug output available: the application must still
+    /// enable it with `glDebugMessageCallback` (and `GL_DEBUG_OUTPUT`) after making
+    /// the context current.
+    ///
+    /// Sets `EGL_CONTEXT_OPENGL_DEBUG` attribute.
+    #[cfg(egl_has_1_5)]
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = Some([egl::context_attrib::EGL_CONTEXT_OPENGL_DEBUG,
+                           if debug {
+                               egl::EGL_TRUE as EGLint
+                           } else {
+                               egl::EGL_FALSE as EGLint
+                           }]);
+        self
+    }
+
+    /// `[EGL_IMG_context_priority]` Requested scheduling priority of the context,
+    /// which controls preemption on GPUs that support it. The default value is
+    /// `ContextPriority::Medium`.
+    ///
+    /// The driver may grant a lower priority than requested; use `Context::priority`
+    /// to query the actual one.
+    ///
+    /// Sets `EGL_CONTEXT_PRIORITY_LEVEL_IMG` attribute.
+    pub fn with_priority(mut self, priority: ContextPriority) -> Self {
+        self.priority = Some([egl::context_attrib::EGL_CONTEXT_PRIORITY_LEVEL_IMG,
+                              priority as EGLint]);
+        self
+    }
+
+    /// Create the rendering context with `eglCreateContext`.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if a priority is set, but the display
+    /// does not support `EGL_IMG_context_priority`.
+    pub fn create(self) -> Result<Context> {
+        if !self.initialized {
+            return Err(Error::DisplayNotInitialized);
+        }
+        if self.priority.is_some() && !self.has_priority_ext {
+            return Err(Error::ExtensionNotSupported("EGL_IMG_context_priority"));
+        }
+
+        let attrib_list: Vec<_> = [self.client_version, self.debug, self.priority]
+                                      .iter()
+                                      .flatten()
+                                      .flatten()
+                                      .chain(&[egl::config_attrib::EGL_NONE])
+                                      .cloned()
+                                      .collect();
+
+        let (share_context, share_token) = match self.share_context {
+            Some((display, _, _)) if display != self.handle => {
+                return Err(Error::MismatchedDisplay);
+            }
+            Some((_, context, token)) => (context, Some(token)),
+            None => (ptr::null_mut(), None),
+        };
+
+        let handle = egl::create_context_with_attribs(self.handle,
+                                                      self.config.handle(),
+                                                      share_context,
+                                                      &attrib_list)
+                         .map_err(Error::from_failed_call)?;
+
+        Ok(Context::from_raw(self.handle, handle)
+               .with_share_token(share_token)
+               .with_display_token(self.display_token))
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use egl;
+use error::Result;
+
+/// `[EGL_EXT_device_base]` Handle of an EGL device, such as a GPU.
+///
+/// Devices are owned by the EGL implementation and are never destroyed, so this
+/// wrapper does not clean up anything when dropped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EglDevice {
+    handle: egl::EGLDeviceEXT,
+}
+
+impl EglDevice {
+    /// Create an `EglDevice` from an existing EGL device handle.
+    pub fn from_raw(handle: egl::EGLDeviceEXT) -> EglDevice {
+        EglDevice { handle }
+    }
+
+    /// Get raw handle.
+    pub fn handle(&self) -> egl::EGLDeviceEXT {
+        self.handle
+    }
+
+    /// `[EGL_EXT_device_query]` Get the space separated list of extensions supported
+    /// by this device.
+    pub fn query_extensions(&self) -> Result<&'static str> {
+        let cstr = egl::query_device_string_ext(self.handle, egl::query_string::EGL_EXTENSIONS)?;
+        Ok(cstr.to_str()?)
+    }
+
+    /// `[EGL_EXT_device_query]` Check if the named extension is supported by this
+    /// device, such as `EGL_EXT_device_drm`.
+    pub fn has_extension(&self, name: &str) -> Result<bool> {
+        Ok(self.query_extensions()?.split_whitespace().any(|e| e == name))
+    }
+
+    /// `[EGL_EXT_device_drm]` Get the path of the DRM device file of this device,
+    /// such as `/dev/dri/card0`.
+    ///
+    /// Returns `None` if the device does not support `EGL_EXT_device_drm`.
+    pub fn drm_device_file(&self) -> Result<Option<&'static str>> {
+        if !self.has_extension("EGL_EXT_device_drm")? {
+            return Ok(None);
+        }
+
+        let cstr = egl::query_device_string_ext(self.handle, egl::device::EGL_DRM_DEVICE_FILE_EXT)?;
+        Ok(Some(cstr.to_str()?))
+    }
+}
@@ -6,16 +6,133 @@
 // copied, modified, or distributed except according to those terms.
 
 use egl;
+use std::cell::Cell;
+#[cfg(egl_has_1_5)]
+use libc::c_void;
+#[cfg(all(unix, egl_has_1_5))]
+use std::os::unix::io::RawFd;
+#[cfg(all(unix, egl_has_1_5))]
+use Image;
+use EglDevice;
+#[cfg(feature = "egl_stream")]
+use Stream;
+use std::convert::TryFrom;
 use std::ptr;
+use std::rc::Rc;
 use error::Result;
-use {Surface, Context, Version, FrameBufferConfigRef, ConfigFilterRef};
+use {Api, Surface, SurfaceKind, SurfaceType, RenderableType, Context, ContextBuilder,
+     PbufferBuilder, Version, FrameBufferConfigRef, ConfigFilterRef};
 use egl::EGLint;
+use error::Error;
+use Sync;
+use trace;
+use frame_buffer_config::returned_config_count;
+#[cfg(feature = "raw-window-handle")]
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ContextClientVersion {
     OpenGlEs1,
     OpenGlEs2,
 }
 
+impl ContextClientVersion {
+    /// Returns the renderable type a config must support to create a context of
+    /// this version.
+    ///
+    /// Pass it to `ConfigFilterRef::with_renderable_type` to choose configs matching
+    /// the context that will be created.
+    pub fn renderable_type(&self) -> RenderableType {
+        match *self {
+            ContextClientVersion::OpenGlEs1 => RenderableType::OPENGL_ES,
+            ContextClientVersion::OpenGlEs2 => RenderableType::OPENGL_ES2,
+        }
+    }
+}
+
+impl TryFrom<RenderableType> for ContextClientVersion {
+    type Error = Error;
+
+    /// Fails with `Error::UnknownValue` unless exactly one of `OPENGL_ES` or
+    /// `OPENGL_ES2` is set.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use egli::{ContextClientVersion, RenderableType};
+    ///
+    /// let version = ContextClientVersion::try_from(RenderableType::OPENGL_ES2).unwrap();
+    /// assert_eq!(version, ContextClientVersion::OpenGlEs2);
+    /// assert_eq!(version.renderable_type(), RenderableType::OPENGL_ES2);
+    ///
+    /// assert!(ContextClientVersion::try_from(RenderableType::OPENGL_ES |
+    ///                                        RenderableType::OPENGL_ES2).is_err());
+    /// ```
+    fn try_from(value: RenderableType) -> Result<ContextClientVersion> {
+        if value == RenderableType::OPENGL_ES {
+            Ok(ContextClientVersion::OpenGlEs1)
+        } else if value == RenderableType::OPENGL_ES2 {
+            Ok(ContextClientVersion::OpenGlEs2)
+        } else {
+            Err(Error::UnknownValue(value.bits()))
+        }
+    }
+}
+
+/// Partial update features supported by a display, returned by
+/// `Display::partial_update_support`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct PartialUpdateSupport {
+    /// `EGL_EXT_buffer_age`: the age of the back buffer can be queried, so only
+    /// the regions changed since that frame need to be redrawn.
+    pub buffer_age: bool,
+    /// `EGL_KHR_swap_buffers_with_damage` or `EGL_EXT_swap_buffers_with_damage`:
+    /// the damaged regions can be passed to the compositor when swapping.
+    pub swap_with_damage: bool,
+    /// `EGL_KHR_partial_update`: rendering can be limited to a damage region with
+    /// `Surface::set_damage_region`.
+    pub set_damage_region: bool,
+}
+
+/// Surfaces and context bound with `Display::make_current_owned`.
+///
+/// When dropped, releases the current context of the calling thread with
+/// `eglMakeCurrent`, then drops the owned surfaces and context.
+#[must_use = "dropping the guard releases the context and destroys the objects immediately"]
+pub struct OwnedCurrentGuard {
+    display_handle: egl::EGLDisplay,
+    draw: Surface,
+    read: Surface,
+    context: Context,
+}
+
+impl Drop for OwnedCurrentGuard {
+    fn drop(&mut self) {
+        let _ = egl::make_current(self.display_handle,
+                                  egl::EGL_NO_SURFACE,
+                                  egl::EGL_NO_SURFACE,
+                                  egl::EGL_NO_CONTEXT);
+    }
+}
+
+impl OwnedCurrentGuard {
+    /// Returns the bound draw surface.
+    pub fn draw(&self) -> &Surface {
+        &self.draw
+    }
+
+    /// Returns the bound read surface.
+    pub fn read(&self) -> &Surface {
+        &self.read
+    }
+
+    /// Returns the bound context.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+}
+
 /// `[EGL 1.0]` [RAII](https://en.wikipedia.org/wiki/Resource_Acquisition_Is_Initialization) wrapper for
 /// EGLDisplay.
 ///
@@ -26,27 +143,47 @@ pub enum ContextClientVersion {
 /// ```
 ///
 /// call. Followed by `eglTerminate`.
+///
+/// A `Display` must outlive all surfaces and contexts created from it, because
+/// they are destroyed using this display connection. In debug builds, dropping the
+/// `Display` while any of them are still alive prints a warning to stderr.
+#[must_use = "dropping a `Display` terminates it immediately"]
 pub struct Display {
     terminated: bool,
     handle: egl::EGLDisplay,
+    children: Rc<()>,
+    // Shared with the copies returned by `borrow`, which refer to the same handle.
+    extensions: Rc<Cell<Option<&'static str>>>,
+    initialized: Rc<Cell<bool>>,
 }
 
 impl Drop for Display {
     fn drop(&mut self) {
         if !self.terminated {
+            trace::trace("Display", "terminated", self.handle);
+
+            let alive = Rc::strong_count(&self.children) - 1;
+            if cfg!(debug_assertions) && alive > 0 {
+                eprintln!("egli: Display dropped while {} surface(s) or context(s) created \
+                           from it are still alive; drop them before the display",
+                          alive);
+            }
+
             // If you are reading this, you may be wondering if the drop panics. It does not.
             // Ignoring errors here might be sub-optimal for some API uses.
             // In that case, use EGL directly, or handle termination by getting handle from
-            // `forget` method.
+            // `into_raw` method.
             let _ = self.make_not_current();
             let _ = egl::terminate(self.handle);
+            self.initialized.set(false);
+            self.extensions.set(None);
         }
     }
 }
 
 impl Into<egl::EGLDisplay> for Display {
     fn into(self) -> egl::EGLDisplay {
-        self.forget()
+        self.into_raw()
     }
 }
 
@@ -64,22 +201,66 @@ impl Display {
     /// default display.
     pub fn from_display_id(display_id: egl::EGLNativeDisplayType) -> Result<Display> {
         match egl::get_display(display_id) {
-            Ok(handle) => {
-                Ok(Display {
-                    terminated: false,
-                    handle: handle,
-                })
-            }
+            Ok(handle) => Ok(Display::from_raw(handle).with_initialized(false)),
             Err(e) => Err(e.into()),
         }
     }
 
+    /// `[EGL 1.5]` Create a `Display` from a native display of the given platform,
+    /// such as `egl::platform::EGL_PLATFORM_X11_EXT`.
+    ///
+    /// `attrib_list` must be empty or terminated with `egl::config_attrib::EGL_NONE`. Use
+    /// `PlatformDisplayBuilder` to build it.
+    #[cfg(egl_has_1_5)]
+    pub fn from_platform_display(platform: egl::EGLenum,
+                                 native_display: *mut c_void,
+                                 attrib_list: &[egl::EGLAttrib])
+                                 -> Result<Display> {
+        let handle = egl::get_platform_display(platform, native_display, attrib_list)?;
+        Ok(Display::from_raw(handle).with_initialized(false))
+    }
+
+    /// Create a `Display` from an existing EGL display handle.
+    ///
+    /// The created `Display` takes ownership of the handle and terminates it when dropped.
+    ///
+    /// The handle is assumed to be initialized already, so `is_initialized` returns `true`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::Display;
+    /// use egli::egl::EGLDisplay;
+    ///
+    /// let handle = 1 as EGLDisplay;
+    ///
+    /// let display = Display::from_raw(handle);
+    /// assert_eq!(display.into_raw(), handle);
+    /// ```
+    pub fn from_raw(handle: egl::EGLDisplay) -> Display {
+        trace::trace("Display", "created", handle);
+        Display {
+            terminated: false,
+            handle,
+            children: Rc::new(()),
+            extensions: Rc::new(Cell::new(None)),
+            initialized: Rc::new(Cell::new(true)),
+        }
+    }
+
+    /// Sets whether this display is known to be initialized.
+    fn with_initialized(self, initialized: bool) -> Display {
+        self.initialized.set(initialized);
+        self
+    }
+
     /// `[EGL 1.0]` Creates a `Display` from the default display.
     ///
-    /// This is a convenience wrapper that calls `Display::from_display_id` with
-    /// `egl::EGL_DEFAULT_DISPLAY` option.
+    /// This is a convenience wrapper that gets the display with
+    /// `egl::NativeDisplay::Default` option.
     pub fn from_default_display() -> Result<Display> {
-        Display::from_display_id(egl::EGL_DEFAULT_DISPLAY)
+        let handle = egl::get_display_typed(egl::NativeDisplay::Default)?;
+        Ok(Display::from_raw(handle).with_initialized(false))
     }
 
     /// `[EGL 1.0]` Initialize this EGL display connection and return EGL version.
@@ -88,14 +269,9 @@ impl Display {
     /// Initializing an already initialized EGL display connection has no effect besides
     /// returning the version numbers.
     pub fn initialize_and_get_version(&self) -> Result<Version> {
-        let (mut major, mut minor) = (0, 0);
-
-        egl::initialize_and_get_version(self.handle, &mut major, &mut minor)?;
-
-        Ok(Version {
-            major: major as i32,
-            minor: minor as i32,
-        })
+        let version = egl::initialize_get_version(self.handle)?;
+        self.initialized.set(true);
+        Ok(version)
     }
 
     /// `[EGL 1.0]` Initialize this EGL display connection.
@@ -105,10 +281,82 @@ impl Display {
     pub fn initialize(&self) -> Result<()> {
 
         egl::initialize(self.handle)?;
+        self.initialized.set(true);
 
         Ok(())
     }
 
+    /// `[EGL 1.0]` Terminate this display connection and initialize it again, keeping
+    /// the same display handle. Returns the EGL version of the new session.
+    ///
+    /// The current context of the calling thread is released first. This can be used to
+    /// recover from a lost device without creating a new `Display`.
+    ///
+    /// All surfaces, contexts and other objects created before the call belong to the
+    /// terminated session and are invalid afterwards. Drop them before calling this
+    /// method; in debug builds, a warning is printed if any of them are still alive.
+    /// Config references must be chosen again, and the cached extension list is
+    /// queried again.
+    pub fn reinitialize(&mut self) -> Result<Version> {
+        let alive = Rc::strong_count(&self.children) - 1;
+        if cfg!(debug_assertions) && alive > 0 {
+            eprintln!("egli: Display reinitialized while {} surface(s) or context(s) \
+                       created from it are still alive; they are no longer valid",
+                      alive);
+        }
+
+        let _ = self.make_not_current();
+        trace::trace("Display", "terminated", self.handle);
+        egl::terminate(self.handle)?;
+        self.initialized.set(false);
+        self.extensions.set(None);
+
+        self.initialize_and_get_version()
+    }
+
+    /// Returns `true` if this display was initialized with `initialize` or
+    /// `initialize_and_get_version`.
+    ///
+    /// Displays created from a display id, a platform display or the default display
+    /// start uninitialized. Displays created with `from_raw` are assumed to be
+    /// initialized.
+    ///
+    /// Surfaces and contexts can only be created on an initialized display. On a
+    /// display that is not, creating them fails with `Error::DisplayNotInitialized`
+    /// without calling EGL.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::{Display, FrameBufferConfigRef};
+    /// use egli::egl::EGLConfig;
+    /// use egli::error::Error;
+    ///
+    /// if let Ok(display) = Display::from_default_display() {
+    ///     assert!(!display.is_initialized());
+    ///
+    ///     // Never passed to EGL, the display is checked first.
+    ///     let config = display.with_handle(|handle| {
+    ///         FrameBufferConfigRef::from_native(handle, 1 as EGLConfig)
+    ///     });
+    ///     assert_eq!(display.create_context(config).err(),
+    ///                Some(Error::DisplayNotInitialized));
+    ///     assert_eq!(display.pbuffer_builder(config).create().err(),
+    ///                Some(Error::DisplayNotInitialized));
+    /// }
+    /// ```
+    pub fn is_initialized(&self) -> bool {
+        self.initialized.get()
+    }
+
+    /// Fails with `Error::DisplayNotInitialized` if this display is not initialized.
+    fn check_initialized(&self) -> Result<()> {
+        if !self.is_initialized() {
+            return Err(Error::DisplayNotInitialized);
+        }
+        Ok(())
+    }
+
     /// `[EGL 1.2]` Query EGL_CLIENT_APIS.
     ///
     /// Returns a string describing which client rendering APIs are supported.
@@ -117,19 +365,36 @@ impl Display {
     /// These strings correspond respectively to values EGL_OPENGL_API, EGL_OPENGL_ES_API, and
     /// EGL_OPENVG_API of the eglBindAPI, api argument.
     pub fn query_client_apis(&self) -> Result<&'static str> {
-        let cstr = egl::query_string(self.handle, egl::EGL_CLIENT_APIS)?;
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_CLIENT_APIS)?;
         Ok(cstr.to_str()?)
     }
 
+    /// `[EGL 1.2]` Same as `query_client_apis`, but replaces invalid UTF-8 sequences
+    /// with `U+FFFD` instead of failing with `Error::NonUtf8StringReceived`.
+    pub fn query_client_apis_lossy(&self) -> Result<String> {
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_CLIENT_APIS)?;
+        Ok(cstr.to_string_lossy().into_owned())
+    }
+
     /// `[EGL 1.0]` Query EGL_VENDOR.
     ///
     /// The vendor-specific information is optional; if present, its format
     /// and contents are implementation specific.
     pub fn query_vendor(&self) -> Result<&'static str> {
-        let cstr = egl::query_string(self.handle, egl::EGL_VENDOR)?;
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_VENDOR)?;
         Ok(cstr.to_str()?)
     }
 
+    /// `[EGL 1.0]` Same as `query_vendor`, but replaces invalid UTF-8 sequences
+    /// with `U+FFFD` instead of failing with `Error::NonUtf8StringReceived`.
+    ///
+    /// Some drivers return vendor strings that are not valid UTF-8; use this when
+    /// the string is only needed for diagnostics.
+    pub fn query_vendor_lossy(&self) -> Result<String> {
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_VENDOR)?;
+        Ok(cstr.to_string_lossy().into_owned())
+    }
+
     /// `[EGL 1.0]` Get supported EGL version for this display.
     ///
     /// Returns a version or release number.
@@ -140,34 +405,178 @@ impl Display {
     /// Both the major and minor portions of the version number are numeric.
     /// Their values must match the major and minor values returned by initialize.
     pub fn query_version(&self) -> Result<&'static str> {
-        let cstr = egl::query_string(self.handle, egl::EGL_VERSION)?;
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_VERSION)?;
         Ok(cstr.to_str()?)
     }
 
+    /// `[EGL 1.0]` Same as `query_version`, but replaces invalid UTF-8 sequences
+    /// with `U+FFFD` instead of failing with `Error::NonUtf8StringReceived`.
+    pub fn query_version_lossy(&self) -> Result<String> {
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_VERSION)?;
+        Ok(cstr.to_string_lossy().into_owned())
+    }
+
+    /// `[EGL 1.0]` Get supported EGL version for this display, parsed from the
+    /// `EGL_VERSION` string returned by `query_version`.
+    ///
+    /// Unlike `initialize_and_get_version`, this can be called at any time after the
+    /// display is initialized. Fails with `Error::InvalidVersionString` if the string
+    /// does not start with `major.minor`.
+    pub fn version_parsed(&self) -> Result<Version> {
+        Ok(self.query_version()?.parse()?)
+    }
+
     /// `[EGL 1.0]` Get the set of display extensions supported by this display.
     ///
     /// Returns a space separated list of supported extensions.
+    ///
+    /// The list does not change after the display is initialized, so it is queried
+    /// once and cached. Use `refresh_extensions` to query it again.
     pub fn query_extensions(&self) -> Result<&'static str> {
-        let cstr = egl::query_string(self.handle, egl::EGL_EXTENSIONS)?;
-        Ok(cstr.to_str()?)
+        match self.extensions.get() {
+            Some(extensions) => Ok(extensions),
+            None => self.refresh_extensions(),
+        }
+    }
+
+    /// `[EGL 1.0]` Same as `query_extensions`, but replaces invalid UTF-8 sequences
+    /// with `U+FFFD` instead of failing with `Error::NonUtf8StringReceived`.
+    ///
+    /// Unlike `query_extensions`, the list is not cached.
+    pub fn query_extensions_lossy(&self) -> Result<String> {
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_EXTENSIONS)?;
+        Ok(cstr.to_string_lossy().into_owned())
+    }
+
+    /// `[EGL 1.0]` Query the set of display extensions again and replace the cached list.
+    ///
+    /// Only needed if the display was terminated and initialized again.
+    pub fn refresh_extensions(&self) -> Result<&'static str> {
+        let cstr = egl::query_string(self.handle, egl::query_string::EGL_EXTENSIONS)?;
+        let extensions = cstr.to_str()?;
+        self.extensions.set(Some(extensions));
+        Ok(extensions)
+    }
+
+    /// `[EGL 1.0]` Check if the named extension is supported by this display.
+    ///
+    /// Looks for an exact match of `name` in the cached `EGL_EXTENSIONS` list.
+    pub fn has_extension(&self, name: &str) -> Result<bool> {
+        Ok(self.query_extensions()?.split_whitespace().any(|e| e == name))
+    }
+
+    /// Checks which partial update extensions this display supports.
+    ///
+    /// Meant to be called once at startup to pick a redraw strategy, instead of
+    /// finding out about missing extensions from failed calls.
+    pub fn partial_update_support(&self) -> Result<PartialUpdateSupport> {
+        Ok(PartialUpdateSupport {
+            buffer_age: self.has_extension("EGL_EXT_buffer_age")?,
+            swap_with_damage: self.has_extension("EGL_KHR_swap_buffers_with_damage")? ||
+                              self.has_extension("EGL_EXT_swap_buffers_with_damage")?,
+            set_damage_region: self.has_extension("EGL_KHR_partial_update")?,
+        })
     }
 
     /// `[EGL 1.0]` Get all possible display configurations.
     ///
-    /// Internally, this calls `eglGetConfigs` twice: to get total config count,
-    /// and to fill allocated memory with config handles.
+    /// Internally, this uses `egl::get_configs_vec`, which calls `eglGetConfigs` twice:
+    /// to get total config count, and to fill allocated memory with config handles.
     ///
     /// These handles are then wrapped into a new `Vec<FrameBufferConfigRef>`.
+    ///
+    /// Fails with `Error::DisplayNotInitialized` if the display is not initialized or
+    /// was terminated.
     pub fn get_configs(&self) -> Result<Vec<FrameBufferConfigRef>> {
-        let count = egl::num_configs(self.handle)? as usize;
+        self.check_initialized()?;
+        Ok(egl::get_configs_vec(self.handle).map_err(Error::from_failed_call)?
+               .into_iter()
+               .map(|c| FrameBufferConfigRef::from_native(self.handle, c))
+               .collect())
+    }
 
-        let mut configs: Vec<egl::EGLConfig> = vec![ptr::null_mut(); count];
-        let returned_count = egl::get_configs(self.handle, &mut configs)? as usize;
+    /// `[EGL 1.0]` Get all possible display configurations, sorted by config ID.
+    ///
+    /// EGL does not guarantee the order of `eglGetConfigs` results, so this is useful
+    /// where a stable order is needed, such as logs and golden tests.
+    ///
+    /// The ID of each config is queried once before sorting.
+    pub fn get_configs_sorted_by_id(&self) -> Result<Vec<FrameBufferConfigRef>> {
+        let mut configs = self.get_configs()?
+                              .into_iter()
+                              .map(|c| c.config_id().map(|id| (id, c)))
+                              .collect::<Result<Vec<_>>>()?;
 
-        Ok(configs[..returned_count]
-               .iter()
-               .map(|c| FrameBufferConfigRef::from_native(self.handle, *c))
-               .collect())
+        configs.sort_by_key(|&(id, _)| id);
+
+        Ok(configs.into_iter().map(|(_, c)| c).collect())
+    }
+
+    /// `[EGL 1.0]` Get the display configuration with the given config ID.
+    ///
+    /// Useful to restore a config choice persisted as its `config_id`. Returns `None`
+    /// if the display has no config with this ID, for example after a driver update.
+    pub fn config_by_id(&self, id: i32) -> Result<Option<FrameBufferConfigRef>> {
+        Ok(self.config_filter()
+               .with_config_id(Some(id))
+               .choose_configs_limited(1)?
+               .into_iter()
+               .next())
+    }
+
+    /// `[EGL 1.0]` Get the display configurations compatible with a native visual.
+    ///
+    /// Useful when the native window is created first, for example by a windowing
+    /// toolkit, and a config matching its visual is needed. `EGL_NATIVE_VISUAL_ID` is
+    /// ignored by `eglChooseConfig`, so all configs are enumerated and compared by
+    /// their `native_visual_id`.
+    pub fn configs_for_visual(&self, visual_id: i32) -> Result<Vec<FrameBufferConfigRef>> {
+        let mut matching = Vec::new();
+        for config in self.get_configs()? {
+            if config.native_visual_id()? == visual_id {
+                matching.push(config);
+            }
+        }
+        Ok(matching)
+    }
+
+    /// `[EGL 1.0]` Get all possible display configurations into an existing buffer.
+    ///
+    /// Same as `get_configs`, but clears and fills the provided `buf`, reusing its
+    /// capacity across calls.
+    ///
+    /// Config handles are read into a buffer on the stack, so nothing else is allocated
+    /// unless the display has more than 256 configs. `eglGetConfigs` can not skip
+    /// configs, so larger counts are read into a temporary heap buffer instead.
+    ///
+    /// Returns the number of configs written.
+    pub fn get_configs_into(&self, buf: &mut Vec<FrameBufferConfigRef>) -> Result<usize> {
+        const STACK_CONFIGS: usize = 256;
+
+        buf.clear();
+        self.check_initialized()?;
+
+        let count = egl::num_configs(self.handle).map_err(Error::from_failed_call)?;
+        buf.reserve(count);
+
+        let mut stack_configs = [ptr::null_mut(); STACK_CONFIGS];
+        let mut heap_configs = Vec::new();
+        let configs: &mut [egl::EGLConfig] = if count <= STACK_CONFIGS {
+            &mut stack_configs[..count]
+        } else {
+            heap_configs.resize(count, ptr::null_mut());
+            &mut heap_configs
+        };
+
+        let returned_count = egl::get_configs(self.handle, configs)
+                                 .map_err(Error::from_failed_call)?;
+        let returned_count = returned_config_count(returned_count, configs.len())?;
+
+        buf.extend(configs[..returned_count]
+                       .iter()
+                       .map(|c| FrameBufferConfigRef::from_native(self.handle, *c)));
+
+        Ok(returned_count)
     }
 
     /// `[EGL 1.0]` Creates a new config filter for this display for safe
@@ -192,76 +601,592 @@ impl Display {
     }
 
     /// `[EGL 1.0]` Create a new EGL window surface.
+    ///
+    /// Fails with `Error::ConfigNotWindowCapable` without calling `eglCreateWindowSurface`
+    /// if the config's surface type does not include `SurfaceType::WINDOW`.
     pub fn create_window_surface(&self,
                                  config: FrameBufferConfigRef,
                                  window: egl::EGLNativeWindowType)
                                  -> Result<Surface> {
+        self.check_initialized()?;
+        if !config.surface_type()?.contains(SurfaceType::WINDOW) {
+            return Err(Error::ConfigNotWindowCapable);
+        }
 
         let maybe_handle = egl::create_window_surface(self.handle, config.handle(), window);
 
-        Ok(Surface::from_handle(self.handle, maybe_handle?))
+        Ok(Surface::from_raw(self.handle, maybe_handle.map_err(Error::from_failed_call)?)
+               .with_kind(SurfaceKind::Window)
+               .with_display_token(self.children.clone())
+               .with_display_extensions(self.extensions.clone()))
     }
 
+    /// `[EGL 1.0]` Create a new EGL window surface for a window of a
+    /// `raw-window-handle` based windowing library, such as `winit`.
+    ///
+    /// If the display supports EGL 1.5, Xlib, Xcb and GBM handles are passed to
+    /// `eglCreatePlatformWindowSurface`, which takes a pointer to the X11 window
+    /// instead of the window itself. Otherwise, and for Win32 and Android NDK handles,
+    /// the window is passed to `create_window_surface` as the native window. Either way
+    /// the display must be of the same platform as the window. Other handles, including
+    /// Wayland ones, which need a `wl_egl_window` created from the surface first, fail
+    /// with `Error::UnsupportedWindowHandle`.
+    ///
+    /// Only available with the `raw-window-handle` feature.
+    #[cfg(feature = "raw-window-handle")]
+    pub fn create_window_surface_from_raw_handle<W: HasRawWindowHandle>(
+        &self,
+        config: FrameBufferConfigRef,
+        window: &W)
+        -> Result<Surface> {
+        self.check_initialized()?;
+
+        #[cfg(egl_has_1_5)]
+        {
+            if self.supports_1_5()? {
+                match window.raw_window_handle() {
+                    RawWindowHandle::Xlib(mut handle) => {
+                        let native_window = &mut handle.window as *mut _ as *mut c_void;
+                        return self.create_platform_window_surface(config, native_window);
+                    }
+                    RawWindowHandle::Xcb(mut handle) => {
+                        let native_window = &mut handle.window as *mut _ as *mut c_void;
+                        return self.create_platform_window_surface(config, native_window);
+                    }
+                    RawWindowHandle::Gbm(handle) => {
+                        return self.create_platform_window_surface(config, handle.gbm_surface);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let native_window = match window.raw_window_handle() {
+            RawWindowHandle::Xlib(handle) => handle.window as usize as egl::EGLNativeWindowType,
+            RawWindowHandle::Xcb(handle) => handle.window as usize as egl::EGLNativeWindowType,
+            RawWindowHandle::Gbm(handle) => handle.gbm_surface as egl::EGLNativeWindowType,
+            RawWindowHandle::Win32(handle) => handle.hwnd as egl::EGLNativeWindowType,
+            RawWindowHandle::AndroidNdk(handle) => {
+                handle.a_native_window as egl::EGLNativeWindowType
+            }
+            _ => return Err(Error::UnsupportedWindowHandle),
+        };
+
+        self.create_window_surface(config, native_window)
+    }
+
+    /// Same as `create_window_surface`, but calls `eglCreatePlatformWindowSurface`, which
+    /// takes a platform specific pointer to the native window.
+    #[cfg(all(feature = "raw-window-handle", egl_has_1_5))]
+    fn create_platform_window_surface(&self,
+                                      config: FrameBufferConfigRef,
+                                      native_window: *mut c_void)
+                                      -> Result<Surface> {
+        if !config.surface_type()?.contains(SurfaceType::WINDOW) {
+            return Err(Error::ConfigNotWindowCapable);
+        }
+
+        let maybe_handle =
+            egl::create_platform_window_surface(self.handle, config.handle(), native_window, &[]);
+
+        Ok(Surface::from_raw(self.handle, maybe_handle.map_err(Error::from_failed_call)?)
+               .with_kind(SurfaceKind::Window)
+               .with_display_token(self.children.clone())
+               .with_display_extensions(self.extensions.clone()))
+    }
+
+    /// `[EGL 1.0]` Create a new EGL pixel buffer surface.
+    ///
+    /// Fails with `Error::ConfigNotPbufferCapable` without calling `eglCreatePbufferSurface`
+    /// if the config's surface type does not include `SurfaceType::PBUFFER`.
     pub fn create_pbuffer_surface(
         &self,
         config: FrameBufferConfigRef,
         attrib_list: &[EGLint],
     ) -> Result<Surface> {
+        self.check_initialized()?;
+        if !config.surface_type()?.contains(SurfaceType::PBUFFER) {
+            return Err(Error::ConfigNotPbufferCapable);
+        }
+
         let maybe_handle = egl::create_pbuffer_surface(self.handle, config.handle(), attrib_list);
 
-        Ok(Surface::from_handle(self.handle, maybe_handle?))
+        Ok(Surface::from_raw(self.handle, maybe_handle.map_err(Error::from_failed_call)?)
+               .with_kind(SurfaceKind::Pbuffer)
+               .with_display_token(self.children.clone()))
     }
 
     /// `[EGL 1.0]` Create a new EGL rendering context.
+    ///
+    /// The context is created for the client API currently bound to the calling
+    /// thread with `eglBindAPI`, which is `Api::OpenGlEs` unless changed. To get a
+    /// desktop OpenGL context, bind `egl::api::EGL_OPENGL_API` first; `current_api` tells
+    /// which API the context will be created for.
     pub fn create_context(&self, config: FrameBufferConfigRef) -> Result<Context> {
-
+        self.check_initialized()?;
         let maybe_handle = egl::create_context(self.handle, config.handle());
 
-        Ok(Context::from_handle(self.handle, maybe_handle?))
+        Ok(Context::from_raw(self.handle, maybe_handle.map_err(Error::from_failed_call)?)
+               .with_display_token(self.children.clone()))
+    }
+
+    /// `[EGL 1.2]` Get the client API bound to the calling thread with `eglBindAPI`.
+    ///
+    /// Contexts created with `create_context` and the other context creation methods
+    /// are of this API. The binding is per thread, not per display, and defaults to
+    /// `Api::OpenGlEs`. Fails with `Error::UnknownValue` if no API is bound, which
+    /// happens when the implementation does not support OpenGL ES and nothing was bound.
+    pub fn current_api(&self) -> Result<Api> {
+        Api::try_from(egl::query_api())
+    }
+
+    /// `[EGL 1.0]` Create a new EGL rendering context that shares data, such as
+    /// textures and buffers, with `share_context`.
+    ///
+    /// Fails with `Error::MismatchedDisplay` if `share_context` belongs to another display.
+    ///
+    /// Keep `share_context` alive for as long as the returned context; in debug builds,
+    /// dropping it first prints a warning.
+    pub fn create_shared_context(&self,
+                                 config: FrameBufferConfigRef,
+                                 share_context: &Context)
+                                 -> Result<Context> {
+        self.context_builder(config)
+            .with_share_context(share_context)
+            .create()
     }
 
     /// `[EGL 1.3]` Create a new EGL rendering context.
+    ///
+    /// Fails with `Error::ConfigLacksRenderableType` without calling `eglCreateContext`
+    /// if the config's renderable type does not include the OpenGL ES version.
     pub fn create_context_with_client_version(&self,
                                               config: FrameBufferConfigRef,
                                               client_version: ContextClientVersion)
                                               -> Result<Context> {
+        self.create_context_with_client_version_and_attribs(config, client_version, &[])
+    }
 
-        let attribs = [egl::EGL_CONTEXT_CLIENT_VERSION,
-                       match client_version {
-                           ContextClientVersion::OpenGlEs1 => 1,
-                           ContextClientVersion::OpenGlEs2 => 2,
-                       },
-                       egl::EGL_NONE];
+    /// `[EGL 1.3]` Create a new EGL rendering context, with `extra` context attributes
+    /// passed to `eglCreateContext` after `EGL_CONTEXT_CLIENT_VERSION`.
+    ///
+    /// `extra` is a list of attribute and value pairs, without the `EGL_NONE`
+    /// terminator, which is appended. It should not contain `EGL_CONTEXT_CLIENT_VERSION`.
+    ///
+    /// Fails with `Error::ConfigLacksRenderableType` without calling `eglCreateContext`
+    /// if the config's renderable type does not include the OpenGL ES version.
+    pub fn create_context_with_client_version_and_attribs(&self,
+                                                          config: FrameBufferConfigRef,
+                                                          client_version: ContextClientVersion,
+                                                          extra: &[EGLint])
+                                                          -> Result<Context> {
+        self.check_initialized()?;
+        let required = client_version.renderable_type();
+        if !config.renderable_type()?.contains(required) {
+            return Err(Error::ConfigLacksRenderableType(required));
+        }
+
+        let mut attribs = Vec::with_capacity(extra.len() + 3);
+        attribs.push(egl::context_attrib::EGL_CONTEXT_CLIENT_VERSION);
+        attribs.push(match client_version {
+            ContextClientVersion::OpenGlEs1 => 1,
+            ContextClientVersion::OpenGlEs2 => 2,
+        });
+        attribs.extend_from_slice(extra);
+        attribs.push(egl::config_attrib::EGL_NONE);
 
         let maybe_handle = egl::create_context_with_attribs(self.handle,
                                                             config.handle(),
                                                             ptr::null_mut(),
                                                             &attribs);
 
-        Ok(Context::from_handle(self.handle, maybe_handle?))
+        Ok(Context::from_raw(self.handle, maybe_handle.map_err(Error::from_failed_call)?)
+               .with_display_token(self.children.clone()))
+    }
+
+    /// `[EGL 1.0]` Creates a new context builder for this display and config.
+    ///
+    /// See documentation of `ContextBuilder` for the list of all available
+    /// context attributes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use egli::Display;
+    ///
+    /// let display = Display::from_default_display()
+    ///                      .expect("failed to get default display");
+    /// display.initialize().expect("failed to initialize");
+    /// let config = display.config_filter()
+    ///                     .choose_configs()
+    ///                     .expect("failed to get configs")[0];
+    /// let context = display.context_builder(config)
+    ///                      .with_client_version(2)
+    ///                      .create();
+    /// ```
+    pub fn context_builder(&self, config: FrameBufferConfigRef) -> ContextBuilder {
+        // Uses the cached extension list; a list that can not be queried is treated
+        // as not having the extension.
+        let has_priority_ext = self.is_initialized() &&
+                               self.has_extension("EGL_IMG_context_priority").unwrap_or(false);
+        ContextBuilder::new(self.handle,
+                            self.children.clone(),
+                            self.is_initialized(),
+                            has_priority_ext,
+                            config)
+    }
+
+    /// `[EGL 1.0]` Creates a new pixel buffer surface builder for this display and config.
+    ///
+    /// See documentation of `PbufferBuilder` for the list of all available
+    /// pbuffer attributes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use egli::{Display, SurfaceType};
+    ///
+    /// let display = Display::from_default_display()
+    ///                      .expect("failed to get default display");
+    /// display.initialize().expect("failed to initialize");
+    /// let config = display.config_filter()
+    ///                     .with_surface_type(SurfaceType::PBUFFER)
+    ///                     .choose_configs()
+    ///                     .expect("failed to get configs")[0];
+    /// let (surface, width, height) = display.pbuffer_builder(config)
+    ///                                       .with_width(4096)
+    ///                                       .with_height(4096)
+    ///                                       .with_largest(true)
+    ///                                       .create()
+    ///                                       .expect("failed to create pbuffer");
+    /// ```
+    pub fn pbuffer_builder(&self, config: FrameBufferConfigRef) -> PbufferBuilder {
+        PbufferBuilder::new(self.handle, self.children.clone(), self.is_initialized(), config)
     }
 
     /// `[EGL 1.0]` Attach an EGL rendering context to EGL surfaces.
+    ///
+    /// Convenience wrapper for `make_current_optional` when all objects are present.
     pub fn make_current(&self, draw: &Surface, read: &Surface, context: &Context) -> Result<()> {
-        egl::make_current(self.handle, draw.handle(), read.handle(), context.handle())?;
+        self.make_current_optional(Some(draw), Some(read), Some(context))
+    }
+
+    /// `[EGL 1.0]` Attach an EGL rendering context to EGL surfaces, taking ownership
+    /// of them for a scoped rendering session.
+    ///
+    /// The returned guard keeps the surfaces and context alive, and releases the
+    /// context before destroying them when dropped. The guard must be dropped on the
+    /// thread that called this method. If binding fails, the objects are dropped.
+    pub fn make_current_owned(&self,
+                              draw: Surface,
+                              read: Surface,
+                              context: Context)
+                              -> Result<OwnedCurrentGuard> {
+        self.make_current(&draw, &read, &context)?;
+        Ok(OwnedCurrentGuard {
+            display_handle: self.handle,
+            draw,
+            read,
+            context,
+        })
+    }
+
+    /// `[EGL 1.0]` Attach an EGL rendering context to EGL surfaces, where any of them
+    /// may be absent.
+    ///
+    /// `None` is passed to `eglMakeCurrent` as `EGL_NO_SURFACE` or `EGL_NO_CONTEXT`.
+    /// This covers binding a context without surfaces (requires EGL 1.5 or
+    /// `EGL_KHR_surfaceless_context`), and releasing the current context when all
+    /// are `None`.
+    pub fn make_current_optional(&self,
+                                 draw: Option<&Surface>,
+                                 read: Option<&Surface>,
+                                 context: Option<&Context>)
+                                 -> Result<()> {
+        egl::make_current(self.handle,
+                          draw.map_or(egl::EGL_NO_SURFACE, |s| s.handle()),
+                          read.map_or(egl::EGL_NO_SURFACE, |s| s.handle()),
+                          context.map_or(egl::EGL_NO_CONTEXT, |c| c.handle()))?;
         Ok(())
     }
 
+    /// `[EGL 1.5]` Returns `true` if a context can be made current without surfaces,
+    /// because the display supports EGL 1.5 or `EGL_KHR_surfaceless_context`.
+    pub fn supports_surfaceless(&self) -> Result<bool> {
+        Ok(self.supports_1_5()? || self.has_extension("EGL_KHR_surfaceless_context")?)
+    }
+
+    /// `[EGL 1.5]` Attach an EGL rendering context without any surfaces, for
+    /// rendering only to framebuffer objects.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if the display supports neither
+    /// EGL 1.5 nor `EGL_KHR_surfaceless_context`.
+    pub fn make_current_surfaceless(&self, context: &Context) -> Result<()> {
+        if !self.supports_surfaceless()? {
+            return Err(Error::ExtensionNotSupported("EGL_KHR_surfaceless_context"));
+        }
+        self.make_current_optional(None, None, Some(context))
+    }
+
     /// `[EGL 1.0]` Detatch an EGL rendering context from EGL surfaces and contexts.
     pub fn make_not_current(&self) -> Result<()> {
-        egl::make_current(self.handle,
-                               egl::EGL_NO_SURFACE,
-                               egl::EGL_NO_SURFACE,
-                               egl::EGL_NO_CONTEXT)?;
+        self.make_current_optional(None, None, None)
+    }
+
+    /// `[EGL 1.2]` Release the current context of the calling thread and then all EGL
+    /// per-thread state with `eglReleaseThread`.
+    ///
+    /// This is the teardown sequence for a rendering thread. Some drivers fail
+    /// `eglReleaseThread` while a context is still current, so the context is released
+    /// first with `make_not_current`.
+    pub fn release_thread(&self) -> Result<()> {
+        self.make_not_current()?;
+        egl::release_thread()?;
         Ok(())
     }
 
     /// `[EGL 1.0]` Post EGL surface color buffer to a native window.
+    ///
+    /// Swapping has no effect on pbuffer and pixmap surfaces.
     pub fn swap_buffers(&self, surface: &Surface) -> Result<()> {
         egl::swap_buffers(self.handle, surface.handle())?;
         Ok(())
     }
 
+    /// `[EGL 1.2]` Complete client API rendering, then post EGL surface color buffer to
+    /// a native window.
+    ///
+    /// The EGL specification requires `eglSwapBuffers` to implicitly flush the current
+    /// context, but some embedded drivers do not, and frames go missing. This calls
+    /// `eglWaitClient` before swapping, which waits for all rendering to the surface
+    /// to complete. That is stronger than a flush, so prefer `swap_buffers` on drivers
+    /// that behave correctly.
+    pub fn swap_buffers_flushed(&self, surface: &Surface) -> Result<()> {
+        egl::wait_client()?;
+        self.swap_buffers(surface)
+    }
+
+    /// `[EGL 1.5]` or `[EGL_KHR_fence_sync]` Insert a fence sync object into the client
+    /// API command stream.
+    ///
+    /// The fence is signalled when all client API commands issued before it are
+    /// complete. Use `Sync::wait_for_completion` to wait for it, instead of the
+    /// heavier `glFinish`.
+    ///
+    /// Calls `eglCreateSync` with `EGL_SYNC_FENCE` type if the display supports EGL 1.5.
+    /// Otherwise falls back to `eglCreateSyncKHR`, and fails with
+    /// `Error::ExtensionNotSupported` if `EGL_KHR_fence_sync` is not available either.
+    /// A client API context must be current on this display.
+    pub fn insert_fence(&self) -> Result<Sync> {
+        self.create_sync(egl::sync::EGL_SYNC_FENCE_KHR)
+    }
+
+    /// `[EGL 1.5]`, `[EGL_KHR_fence_sync]` or `[EGL 1.2]` Block the calling thread until
+    /// all client API commands issued so far are complete.
+    ///
+    /// Inserts a fence with `insert_fence` and waits for it. If neither EGL 1.5 nor
+    /// `EGL_KHR_fence_sync` is available, falls back to `eglWaitClient`, which is
+    /// coarser grained: it only waits for rendering to the current context's surface,
+    /// and may be as costly as `glFinish`. A client API context must be current on
+    /// this display.
+    pub fn wait_for_gpu(&self) -> Result<()> {
+        match self.insert_fence() {
+            Ok(fence) => {
+                fence.wait_for_completion(None)?;
+            }
+            Err(Error::ExtensionNotSupported(_)) => {
+                egl::wait_client()?;
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+
+    /// `[EGL_ANDROID_native_fence_sync]` Insert a native fence sync object into the
+    /// client API command stream.
+    ///
+    /// The native fence file descriptor can be exported with `Sync::dup_native_fence_fd`,
+    /// once the client API commands are flushed.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if `EGL_ANDROID_native_fence_sync` is
+    /// not available. A client API context must be current on this display.
+    #[cfg(all(android, egl_has_1_5))]
+    pub fn insert_native_fence(&self) -> Result<Sync> {
+        if !self.has_extension("EGL_ANDROID_native_fence_sync")? {
+            return Err(Error::ExtensionNotSupported("EGL_ANDROID_native_fence_sync"));
+        }
+
+        self.create_sync(egl::sync::EGL_SYNC_NATIVE_FENCE_ANDROID)
+    }
+
+    /// Creates a sync object with core EGL 1.5 functions if the display supports them,
+    /// otherwise with `EGL_KHR_fence_sync` functions.
+    fn create_sync(&self, sync_type: EGLint) -> Result<Sync> {
+        #[cfg(egl_has_1_5)]
+        {
+            if self.supports_1_5()? {
+                let handle = egl::create_sync(self.handle, sync_type as egl::EGLenum, &[])?;
+
+                return Ok(Sync::from_raw(self.handle, handle)
+                              .with_display_token(self.children.clone()));
+            }
+        }
+
+        if !self.has_extension("EGL_KHR_fence_sync")? {
+            return Err(Error::ExtensionNotSupported("EGL_KHR_fence_sync"));
+        }
+
+        let handle = egl::create_sync_khr(self.handle, sync_type as egl::EGLenum, &[])?;
+
+        Ok(Sync::from_raw_khr(self.handle, handle).with_display_token(self.children.clone()))
+    }
+
+    fn supports_1_5(&self) -> Result<bool> {
+        Ok(match self.query_version()?.parse::<Version>() {
+            Ok(version) => version >= Version { major: 1, minor: 5 },
+            Err(_) => false,
+        })
+    }
+
+    /// `[EGL_KHR_stream]` Create a new stream, to connect a producer and a consumer to.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if the extension is not available.
+    ///
+    /// Only available with the `egl_stream` feature.
+    #[cfg(feature = "egl_stream")]
+    pub fn create_stream(&self) -> Result<Stream> {
+        if !self.has_extension("EGL_KHR_stream")? {
+            return Err(Error::ExtensionNotSupported("EGL_KHR_stream"));
+        }
+
+        let handle = egl::create_stream_khr(self.handle, &[])?;
+
+        Ok(Stream::from_raw(self.handle, handle).with_display_token(self.children.clone()))
+    }
+
+    /// `[EGL_EXT_device_query]` Get the device, such as a GPU, backing this display.
+    ///
+    /// Useful to report which GPU a display is actually using. Returns `None` if the
+    /// `EGL_EXT_device_query` client extension is not supported.
+    pub fn device(&self) -> Result<Option<EglDevice>> {
+        if !::has_client_extension("EGL_EXT_device_query")? {
+            return Ok(None);
+        }
+
+        let handle = egl::query_display_attrib_ext(self.handle, egl::device::EGL_DEVICE_EXT)?;
+        if handle == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(EglDevice::from_raw(handle as egl::EGLDeviceEXT)))
+    }
+
+    /// `[EGL_KHR_debug]` Attach a label to this display, which is passed to the callback
+    /// set with `egl::set_debug_callback` with messages about the display.
+    ///
+    /// EGL keeps referring to the label, so the string is never freed; label a display
+    /// once. Only available with the `egl_debug` feature.
+    #[cfg(feature = "egl_debug")]
+    pub fn label(&self, name: &str) -> Result<()> {
+        egl::label_object_str(self.handle, egl::debug::EGL_OBJECT_DISPLAY_KHR, self.handle, name)
+    }
+
+    /// `[EGL_EXT_image_dma_buf_import_modifiers]` Get the dmabuf formats supported by
+    /// this display, as DRM fourcc codes.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if the extension is not available.
+    pub fn query_dmabuf_formats(&self) -> Result<Vec<u32>> {
+        if !self.has_extension("EGL_EXT_image_dma_buf_import_modifiers")? {
+            return Err(Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import_modifiers"));
+        }
+
+        let count = egl::query_dmabuf_formats_ext(self.handle, &mut [])?.max(0) as usize;
+
+        let mut formats: Vec<EGLint> = vec![0; count];
+        let returned_count = egl::query_dmabuf_formats_ext(self.handle, &mut formats)?;
+        formats.truncate(returned_count.max(0) as usize);
+
+        Ok(formats.into_iter().map(|f| f as u32).collect())
+    }
+
+    /// `[EGL_EXT_image_dma_buf_import_modifiers]` Get the format modifiers supported by
+    /// this display for the dmabuf `format`, a DRM fourcc code.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if the extension is not available.
+    pub fn query_dmabuf_modifiers(&self, format: u32) -> Result<Vec<u64>> {
+        if !self.has_extension("EGL_EXT_image_dma_buf_import_modifiers")? {
+            return Err(Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import_modifiers"));
+        }
+
+        let format = format as EGLint;
+        let count = egl::query_dmabuf_modifiers_ext(self.handle, format, &mut [], &mut [])?
+                        .max(0) as usize;
+
+        let mut modifiers: Vec<egl::EGLuint64KHR> = vec![0; count];
+        let returned_count =
+            egl::query_dmabuf_modifiers_ext(self.handle, format, &mut modifiers, &mut [])?;
+        modifiers.truncate(returned_count.max(0) as usize);
+
+        Ok(modifiers)
+    }
+
+    /// `[EGL 1.5]` `[EGL_EXT_image_dma_buf_import]` Import a single plane dmabuf as an
+    /// `Image`.
+    ///
+    /// `fourcc` is the DRM format of the buffer, `stride` is the plane pitch in bytes and
+    /// `offset` is the plane offset in bytes. If `modifier` is set, it is passed as the
+    /// plane format modifier, which also requires
+    /// `EGL_EXT_image_dma_buf_import_modifiers`.
+    ///
+    /// EGL does not take ownership of `fd`; it can be closed after the image is created.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if a required extension is not available.
+    #[cfg(all(unix, egl_has_1_5))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn import_dmabuf(&self,
+                         fd: RawFd,
+                         width: u32,
+                         height: u32,
+                         fourcc: u32,
+                         stride: u32,
+                         offset: u32,
+                         modifier: Option<u64>)
+                         -> Result<Image> {
+        if !self.has_extension("EGL_EXT_image_dma_buf_import")? {
+            return Err(Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import"));
+        }
+        if modifier.is_some() && !self.has_extension("EGL_EXT_image_dma_buf_import_modifiers")? {
+            return Err(Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import_modifiers"));
+        }
+
+        let mut attrib_list: Vec<egl::EGLAttrib> =
+            vec![egl::surface_attrib::EGL_WIDTH as egl::EGLAttrib, width as egl::EGLAttrib,
+                 egl::surface_attrib::EGL_HEIGHT as egl::EGLAttrib, height as egl::EGLAttrib,
+                 egl::dmabuf::EGL_LINUX_DRM_FOURCC_EXT as egl::EGLAttrib, fourcc as egl::EGLAttrib,
+                 egl::dmabuf::EGL_DMA_BUF_PLANE0_FD_EXT as egl::EGLAttrib, fd as egl::EGLAttrib,
+                 egl::dmabuf::EGL_DMA_BUF_PLANE0_OFFSET_EXT as egl::EGLAttrib,
+                 offset as egl::EGLAttrib,
+                 egl::dmabuf::EGL_DMA_BUF_PLANE0_PITCH_EXT as egl::EGLAttrib,
+                 stride as egl::EGLAttrib];
+
+        if let Some(modifier) = modifier {
+            attrib_list.extend(&[egl::dmabuf::EGL_DMA_BUF_PLANE0_MODIFIER_LO_EXT as egl::EGLAttrib,
+                                 (modifier & 0xFFFF_FFFF) as egl::EGLAttrib,
+                                 egl::dmabuf::EGL_DMA_BUF_PLANE0_MODIFIER_HI_EXT as egl::EGLAttrib,
+                                 (modifier >> 32) as egl::EGLAttrib]);
+        }
+
+        attrib_list.push(egl::config_attrib::EGL_NONE as egl::EGLAttrib);
+
+        let handle = egl::create_image(self.handle,
+                                       egl::EGL_NO_CONTEXT,
+                                       egl::dmabuf::EGL_LINUX_DMA_BUF_EXT,
+                                       ptr::null_mut(),
+                                       &attrib_list)?;
+
+        Ok(Image::from_raw(self.handle, handle).with_display_token(self.children.clone()))
+    }
+
     /// Run an action with inner handle as parameter.
     pub fn with_handle<F, R>(&self, action: F) -> R
         where F: FnOnce(egl::EGLDisplay) -> R
@@ -269,13 +1194,77 @@ impl Display {
         action(self.handle)
     }
 
+    /// Returns a non-owning copy of this `Display`.
+    ///
+    /// The copy refers to the same `EGLDisplay` handle, but does not terminate it
+    /// when dropped. Exactly one owner, normally the original `Display`, should be
+    /// responsible for terminating the display.
+    ///
+    /// The copy is not tied to the lifetime of this `Display`: after the owning
+    /// `Display` is dropped, the copy refers to a terminated display. Surfaces and
+    /// contexts created from the copy are not counted by the owner's drop check.
+    ///
+    /// The copy shares the initialization state and the cached extension list with
+    /// this `Display`, so it sees later calls to `initialize`, `reinitialize` and
+    /// `refresh_extensions` on either of them.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::Display;
+    ///
+    /// if let Ok(display) = Display::from_default_display() {
+    ///     let borrowed = display.borrow();
+    ///     assert!(!borrowed.is_initialized());
+    ///
+    ///     if display.initialize().is_ok() {
+    ///         assert!(borrowed.is_initialized());
+    ///     }
+    ///
+    ///     drop(display);
+    ///     assert!(!borrowed.is_initialized());
+    /// }
+    /// ```
+    pub fn borrow(&self) -> Display {
+        Display {
+            terminated: true,
+            handle: self.handle,
+            children: Rc::new(()),
+            extensions: self.extensions.clone(),
+            initialized: self.initialized.clone(),
+        }
+    }
+
+    /// Disables cleanup of this `Display` and leaks it, returning a `'static` reference.
+    ///
+    /// Unlike `into_raw`, the wrapper stays usable, for when C code takes ownership of
+    /// the handle but Rust code keeps calling methods on it. The `Display` is never
+    /// dropped, and `eglTerminate` is never called for it; the C side is responsible
+    /// for that. Methods called after the C side terminates the display will fail or,
+    /// if EGL reuses the handle, affect an unrelated display.
+    ///
+    /// The memory of the wrapper itself is leaked, so use this only for
+    /// long-lived displays.
+    pub fn leak(mut self) -> &'static Display {
+        self.terminated = true;
+        Box::leak(Box::new(self))
+    }
+
     /// Drops `Display` without cleaning up any resources.
     ///
     /// Returns `EGLDisplay` handle.
     ///
     /// Alias for `Into<egl::EGLDisplay>`.
-    pub fn forget(mut self) -> egl::EGLDisplay {
+    pub fn into_raw(mut self) -> egl::EGLDisplay {
         self.terminated = true;
         self.handle
     }
+
+    /// Drops `Display` without cleaning up any resources.
+    ///
+    /// Returns `EGLDisplay` handle.
+    #[deprecated(note = "use `Display::into_raw`")]
+    pub fn forget(self) -> egl::EGLDisplay {
+        self.into_raw()
+    }
 }
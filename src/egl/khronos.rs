@@ -26,10 +26,13 @@ use libc::{c_float};
 
 pub type khronos_float_t = c_float;
 
-pub type khronos_intptr_t = i32;
-pub type khronos_uintptr_t = u32;
-pub type khronos_ssize_t = i32;
-pub type khronos_usize_t = u32;
+pub type khronos_intptr_t = isize;
+pub type khronos_uintptr_t = usize;
+pub type khronos_ssize_t = isize;
+pub type khronos_usize_t = usize;
+
+pub type khronos_int64_t = i64;
+pub type khronos_uint64_t = u64;
 
 pub type khronos_stime_nanoseconds_t = i64;
 pub type khronos_utime_nanoseconds_t = u64;
@@ -34,10 +34,19 @@ use std::mem;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::ptr;
+use std::sync::OnceLock;
 use ffi;
-use error::{EglCallError, EglCallResult};
+use error::{self, EglCallError, EglCallResult};
+use Version;
 
-use libc::{c_uint, c_void};
+use libc::{c_char, c_uint, c_void};
+
+#[cfg(feature = "egl_debug")]
+use std::collections::HashSet;
+#[cfg(feature = "egl_debug")]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "egl_debug")]
+use std::sync::{Arc, Mutex};
 
 // -------------------------------------------------------------------------------------------------
 // LINKING
@@ -59,15 +68,29 @@ pub type EGLenum = c_uint;
 pub type EGLint = i32;
 pub type EGLNativeDisplayType = *mut c_void;
 pub type EGLSurface = *mut c_void;
+// EGL 1.5, also used by EGL_KHR_debug
+pub type EGLAttrib = khronos::khronos_intptr_t;
 // EGL 1.5
-#[cfg(feature = "egl_1_5")]
+#[cfg(egl_has_1_5)]
 pub type EGLSync = *mut c_void;
-#[cfg(feature = "egl_1_5")]
-pub type EGLAttrib = khronos::khronos_intptr_t;
-#[cfg(feature = "egl_1_5")]
+#[cfg(egl_has_1_5)]
 pub type EGLTime = khronos::khronos_utime_nanoseconds_t;
-#[cfg(feature = "egl_1_5")]
+#[cfg(egl_has_1_5)]
 pub type EGLImage = *mut c_void;
+// EGL_KHR_fence_sync
+pub type EGLSyncKHR = *mut c_void;
+pub type EGLTimeKHR = khronos::khronos_utime_nanoseconds_t;
+pub type EGLuint64KHR = khronos::khronos_uint64_t;
+// EGL_EXT_device_base
+pub type EGLDeviceEXT = *mut c_void;
+// EGL_KHR_debug
+pub type EGLLabelKHR = *mut c_void;
+pub type EGLObjectKHR = *mut c_void;
+// EGL_KHR_stream
+#[cfg(feature = "egl_stream")]
+pub type EGLStreamKHR = *mut c_void;
+// EGL_ANDROID_get_frame_timestamps
+pub type EGLnsecsANDROID = khronos::khronos_stime_nanoseconds_t;
 
 // -------------------------------------------------------------------------------------------------
 // ANDROID TYPES
@@ -97,6 +120,85 @@ pub type EGLNativePixmapType = *mut c_void;
 #[cfg(not(android))]
 pub type EGLNativeWindowType = *mut c_void;
 
+// -------------------------------------------------------------------------------------------------
+// NATIVE DISPLAY
+// -------------------------------------------------------------------------------------------------
+
+/// Native display to get an EGL display connection for, with `get_display_typed`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NativeDisplay {
+    /// The default display, `EGL_DEFAULT_DISPLAY`.
+    Default,
+    /// A native display handle, such as an X11 `Display*`.
+    Raw(EGLNativeDisplayType),
+}
+
+impl NativeDisplay {
+    /// Returns the `EGLNativeDisplayType` value passed to `eglGetDisplay`.
+    pub fn as_raw(&self) -> EGLNativeDisplayType {
+        match *self {
+            NativeDisplay::Default => EGL_DEFAULT_DISPLAY,
+            NativeDisplay::Raw(display_id) => display_id,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// NATIVE ENGINE
+// -------------------------------------------------------------------------------------------------
+
+/// Native rendering engine to wait for, with `wait_native_typed`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NativeEngine {
+    /// The core native engine, `EGL_CORE_NATIVE_ENGINE`, the only engine defined by EGL.
+    Core,
+    /// A vendor specific engine defined by an extension.
+    Vendor(EGLint),
+}
+
+impl NativeEngine {
+    /// Returns the `EGLint` value passed to `eglWaitNative`.
+    pub fn as_raw(&self) -> EGLint {
+        match *self {
+            NativeEngine::Core => EGL_CORE_NATIVE_ENGINE,
+            NativeEngine::Vendor(engine) => engine,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// CURRENT STATE
+// -------------------------------------------------------------------------------------------------
+
+/// Binding that was current to the calling thread before `make_current_saving`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[must_use = "the previous binding is only restored by calling `restore`"]
+pub struct PreviousCurrent {
+    /// Display passed to `make_current_saving`, used to release the context
+    /// if nothing was current before.
+    pub display: EGLDisplay,
+    /// Previously current display, or `EGL_NO_DISPLAY`.
+    pub previous_display: EGLDisplay,
+    /// Previously current draw surface, or `EGL_NO_SURFACE`.
+    pub draw: EGLSurface,
+    /// Previously current read surface, or `EGL_NO_SURFACE`.
+    pub read: EGLSurface,
+    /// Previously current context, or `EGL_NO_CONTEXT`.
+    pub context: EGLContext,
+}
+
+impl PreviousCurrent {
+    /// `[EGL 1.0]` Re-binds the previously current context and surfaces, or releases
+    /// the current context if none was current.
+    pub fn restore(self) -> EglCallResult<()> {
+        if self.context.is_null() || self.previous_display.is_null() {
+            make_current(self.display, EGL_NO_SURFACE, EGL_NO_SURFACE, EGL_NO_CONTEXT)
+        } else {
+            make_current(self.previous_display, self.draw, self.read, self.context)
+        }
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // CONSTANTS
 // -------------------------------------------------------------------------------------------------
@@ -112,137 +214,232 @@ pub const EGL_NO_DISPLAY: EGLDisplay = 0 as *mut c_void;
 pub const EGL_NO_SURFACE: EGLSurface = 0 as *mut c_void;
 
 // out-of-band attribute value
-pub const EGL_DONT_CARE: EGLint = -1;
+#[deprecated(note = "use `egl::config_attrib::EGL_DONT_CARE`")]
+pub const EGL_DONT_CARE: EGLint = config_attrib::EGL_DONT_CARE;
 
 // errors / GetError return values
-pub const EGL_SUCCESS: EGLint = 0x3000;
-pub const EGL_NOT_INITIALIZED: EGLint = 0x3001;
-pub const EGL_BAD_ACCESS: EGLint = 0x3002;
-pub const EGL_BAD_ALLOC: EGLint = 0x3003;
-pub const EGL_BAD_ATTRIBUTE: EGLint = 0x3004;
-pub const EGL_BAD_CONFIG: EGLint = 0x3005;
-pub const EGL_BAD_CONTEXT: EGLint = 0x3006;
-pub const EGL_BAD_CURRENT_SURFACE: EGLint = 0x3007;
-pub const EGL_BAD_DISPLAY: EGLint = 0x3008;
-pub const EGL_BAD_MATCH: EGLint = 0x3009;
-pub const EGL_BAD_NATIVE_PIXMAP: EGLint = 0x300A;
-pub const EGL_BAD_NATIVE_WINDOW: EGLint = 0x300B;
-pub const EGL_BAD_PARAMETER: EGLint = 0x300C;
-pub const EGL_BAD_SURFACE: EGLint = 0x300D;
-pub const EGL_CONTEXT_LOST: EGLint = 0x300E;  // EGL 1.1 - IMG_power_management
+#[deprecated(note = "use `egl::error_code::EGL_SUCCESS`")]
+pub const EGL_SUCCESS: EGLint = error_code::EGL_SUCCESS;
+#[deprecated(note = "use `egl::error_code::EGL_NOT_INITIALIZED`")]
+pub const EGL_NOT_INITIALIZED: EGLint = error_code::EGL_NOT_INITIALIZED;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_ACCESS`")]
+pub const EGL_BAD_ACCESS: EGLint = error_code::EGL_BAD_ACCESS;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_ALLOC`")]
+pub const EGL_BAD_ALLOC: EGLint = error_code::EGL_BAD_ALLOC;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_ATTRIBUTE`")]
+pub const EGL_BAD_ATTRIBUTE: EGLint = error_code::EGL_BAD_ATTRIBUTE;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_CONFIG`")]
+pub const EGL_BAD_CONFIG: EGLint = error_code::EGL_BAD_CONFIG;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_CONTEXT`")]
+pub const EGL_BAD_CONTEXT: EGLint = error_code::EGL_BAD_CONTEXT;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_CURRENT_SURFACE`")]
+pub const EGL_BAD_CURRENT_SURFACE: EGLint = error_code::EGL_BAD_CURRENT_SURFACE;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_DISPLAY`")]
+pub const EGL_BAD_DISPLAY: EGLint = error_code::EGL_BAD_DISPLAY;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_MATCH`")]
+pub const EGL_BAD_MATCH: EGLint = error_code::EGL_BAD_MATCH;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_NATIVE_PIXMAP`")]
+pub const EGL_BAD_NATIVE_PIXMAP: EGLint = error_code::EGL_BAD_NATIVE_PIXMAP;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_NATIVE_WINDOW`")]
+pub const EGL_BAD_NATIVE_WINDOW: EGLint = error_code::EGL_BAD_NATIVE_WINDOW;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_PARAMETER`")]
+pub const EGL_BAD_PARAMETER: EGLint = error_code::EGL_BAD_PARAMETER;
+#[deprecated(note = "use `egl::error_code::EGL_BAD_SURFACE`")]
+pub const EGL_BAD_SURFACE: EGLint = error_code::EGL_BAD_SURFACE;
+#[deprecated(note = "use `egl::error_code::EGL_CONTEXT_LOST`")]
+pub const EGL_CONTEXT_LOST: EGLint = error_code::EGL_CONTEXT_LOST;
 
 // config attributes
-pub const EGL_BUFFER_SIZE: EGLint = 0x3020;
-pub const EGL_ALPHA_SIZE: EGLint = 0x3021;
-pub const EGL_BLUE_SIZE: EGLint = 0x3022;
-pub const EGL_GREEN_SIZE: EGLint = 0x3023;
-pub const EGL_RED_SIZE: EGLint = 0x3024;
-pub const EGL_DEPTH_SIZE: EGLint = 0x3025;
-pub const EGL_STENCIL_SIZE: EGLint = 0x3026;
-pub const EGL_CONFIG_CAVEAT: EGLint = 0x3027;
-pub const EGL_CONFIG_ID: EGLint = 0x3028;
-pub const EGL_LEVEL: EGLint = 0x3029;
-pub const EGL_MAX_PBUFFER_HEIGHT: EGLint = 0x302A;
-pub const EGL_MAX_PBUFFER_PIXELS: EGLint = 0x302B;
-pub const EGL_MAX_PBUFFER_WIDTH: EGLint = 0x302C;
-pub const EGL_NATIVE_RENDERABLE: EGLint = 0x302D;
-pub const EGL_NATIVE_VISUAL_ID: EGLint = 0x302E;
-pub const EGL_NATIVE_VISUAL_TYPE: EGLint = 0x302F;
-pub const EGL_SAMPLES: EGLint = 0x3031;
-pub const EGL_SAMPLE_BUFFERS: EGLint = 0x3032;
-pub const EGL_SURFACE_TYPE: EGLint = 0x3033;
-pub const EGL_TRANSPARENT_TYPE: EGLint = 0x3034;
-pub const EGL_TRANSPARENT_BLUE_VALUE: EGLint = 0x3035;
-pub const EGL_TRANSPARENT_GREEN_VALUE: EGLint = 0x3036;
-pub const EGL_TRANSPARENT_RED_VALUE: EGLint = 0x3037;
-pub const EGL_NONE: EGLint = 0x3038; // attrib list terminator
-pub const EGL_BIND_TO_TEXTURE_RGB: EGLint = 0x3039;
-pub const EGL_BIND_TO_TEXTURE_RGBA: EGLint = 0x303A;
-pub const EGL_MIN_SWAP_INTERVAL: EGLint = 0x303B;
-pub const EGL_MAX_SWAP_INTERVAL: EGLint = 0x303C;
-pub const EGL_LUMINANCE_SIZE: EGLint = 0x303D;
-pub const EGL_ALPHA_MASK_SIZE: EGLint = 0x303E;
-pub const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
-pub const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
-pub const EGL_MATCH_NATIVE_PIXMAP: EGLint = 0x3041;  // psseudo-attribute (not queryable)
-pub const EGL_CONFORMANT: EGLint = 0x3042;
+#[deprecated(note = "use `egl::config_attrib::EGL_BUFFER_SIZE`")]
+pub const EGL_BUFFER_SIZE: EGLint = config_attrib::EGL_BUFFER_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_ALPHA_SIZE`")]
+pub const EGL_ALPHA_SIZE: EGLint = config_attrib::EGL_ALPHA_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_BLUE_SIZE`")]
+pub const EGL_BLUE_SIZE: EGLint = config_attrib::EGL_BLUE_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_GREEN_SIZE`")]
+pub const EGL_GREEN_SIZE: EGLint = config_attrib::EGL_GREEN_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_RED_SIZE`")]
+pub const EGL_RED_SIZE: EGLint = config_attrib::EGL_RED_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_DEPTH_SIZE`")]
+pub const EGL_DEPTH_SIZE: EGLint = config_attrib::EGL_DEPTH_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_STENCIL_SIZE`")]
+pub const EGL_STENCIL_SIZE: EGLint = config_attrib::EGL_STENCIL_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_CONFIG_CAVEAT`")]
+pub const EGL_CONFIG_CAVEAT: EGLint = config_attrib::EGL_CONFIG_CAVEAT;
+#[deprecated(note = "use `egl::config_attrib::EGL_CONFIG_ID`")]
+pub const EGL_CONFIG_ID: EGLint = config_attrib::EGL_CONFIG_ID;
+#[deprecated(note = "use `egl::config_attrib::EGL_LEVEL`")]
+pub const EGL_LEVEL: EGLint = config_attrib::EGL_LEVEL;
+#[deprecated(note = "use `egl::config_attrib::EGL_MAX_PBUFFER_HEIGHT`")]
+pub const EGL_MAX_PBUFFER_HEIGHT: EGLint = config_attrib::EGL_MAX_PBUFFER_HEIGHT;
+#[deprecated(note = "use `egl::config_attrib::EGL_MAX_PBUFFER_PIXELS`")]
+pub const EGL_MAX_PBUFFER_PIXELS: EGLint = config_attrib::EGL_MAX_PBUFFER_PIXELS;
+#[deprecated(note = "use `egl::config_attrib::EGL_MAX_PBUFFER_WIDTH`")]
+pub const EGL_MAX_PBUFFER_WIDTH: EGLint = config_attrib::EGL_MAX_PBUFFER_WIDTH;
+#[deprecated(note = "use `egl::config_attrib::EGL_NATIVE_RENDERABLE`")]
+pub const EGL_NATIVE_RENDERABLE: EGLint = config_attrib::EGL_NATIVE_RENDERABLE;
+#[deprecated(note = "use `egl::config_attrib::EGL_NATIVE_VISUAL_ID`")]
+pub const EGL_NATIVE_VISUAL_ID: EGLint = config_attrib::EGL_NATIVE_VISUAL_ID;
+#[deprecated(note = "use `egl::config_attrib::EGL_NATIVE_VISUAL_TYPE`")]
+pub const EGL_NATIVE_VISUAL_TYPE: EGLint = config_attrib::EGL_NATIVE_VISUAL_TYPE;
+#[deprecated(note = "use `egl::config_attrib::EGL_SAMPLES`")]
+pub const EGL_SAMPLES: EGLint = config_attrib::EGL_SAMPLES;
+#[deprecated(note = "use `egl::config_attrib::EGL_SAMPLE_BUFFERS`")]
+pub const EGL_SAMPLE_BUFFERS: EGLint = config_attrib::EGL_SAMPLE_BUFFERS;
+#[deprecated(note = "use `egl::config_attrib::EGL_SURFACE_TYPE`")]
+pub const EGL_SURFACE_TYPE: EGLint = config_attrib::EGL_SURFACE_TYPE;
+#[deprecated(note = "use `egl::config_attrib::EGL_TRANSPARENT_TYPE`")]
+pub const EGL_TRANSPARENT_TYPE: EGLint = config_attrib::EGL_TRANSPARENT_TYPE;
+#[deprecated(note = "use `egl::config_attrib::EGL_TRANSPARENT_BLUE_VALUE`")]
+pub const EGL_TRANSPARENT_BLUE_VALUE: EGLint = config_attrib::EGL_TRANSPARENT_BLUE_VALUE;
+#[deprecated(note = "use `egl::config_attrib::EGL_TRANSPARENT_GREEN_VALUE`")]
+pub const EGL_TRANSPARENT_GREEN_VALUE: EGLint = config_attrib::EGL_TRANSPARENT_GREEN_VALUE;
+#[deprecated(note = "use `egl::config_attrib::EGL_TRANSPARENT_RED_VALUE`")]
+pub const EGL_TRANSPARENT_RED_VALUE: EGLint = config_attrib::EGL_TRANSPARENT_RED_VALUE;
+#[deprecated(note = "use `egl::config_attrib::EGL_NONE`")]
+pub const EGL_NONE: EGLint = config_attrib::EGL_NONE;
+#[deprecated(note = "use `egl::config_attrib::EGL_BIND_TO_TEXTURE_RGB`")]
+pub const EGL_BIND_TO_TEXTURE_RGB: EGLint = config_attrib::EGL_BIND_TO_TEXTURE_RGB;
+#[deprecated(note = "use `egl::config_attrib::EGL_BIND_TO_TEXTURE_RGBA`")]
+pub const EGL_BIND_TO_TEXTURE_RGBA: EGLint = config_attrib::EGL_BIND_TO_TEXTURE_RGBA;
+#[deprecated(note = "use `egl::config_attrib::EGL_MIN_SWAP_INTERVAL`")]
+pub const EGL_MIN_SWAP_INTERVAL: EGLint = config_attrib::EGL_MIN_SWAP_INTERVAL;
+#[deprecated(note = "use `egl::config_attrib::EGL_MAX_SWAP_INTERVAL`")]
+pub const EGL_MAX_SWAP_INTERVAL: EGLint = config_attrib::EGL_MAX_SWAP_INTERVAL;
+#[deprecated(note = "use `egl::config_attrib::EGL_LUMINANCE_SIZE`")]
+pub const EGL_LUMINANCE_SIZE: EGLint = config_attrib::EGL_LUMINANCE_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_ALPHA_MASK_SIZE`")]
+pub const EGL_ALPHA_MASK_SIZE: EGLint = config_attrib::EGL_ALPHA_MASK_SIZE;
+#[deprecated(note = "use `egl::config_attrib::EGL_COLOR_BUFFER_TYPE`")]
+pub const EGL_COLOR_BUFFER_TYPE: EGLint = config_attrib::EGL_COLOR_BUFFER_TYPE;
+#[deprecated(note = "use `egl::config_attrib::EGL_RENDERABLE_TYPE`")]
+pub const EGL_RENDERABLE_TYPE: EGLint = config_attrib::EGL_RENDERABLE_TYPE;
+#[deprecated(note = "use `egl::config_attrib::EGL_MATCH_NATIVE_PIXMAP`")]
+pub const EGL_MATCH_NATIVE_PIXMAP: EGLint = config_attrib::EGL_MATCH_NATIVE_PIXMAP;
+#[deprecated(note = "use `egl::config_attrib::EGL_CONFORMANT`")]
+pub const EGL_CONFORMANT: EGLint = config_attrib::EGL_CONFORMANT;
 
 // config attribute values
-pub const EGL_SLOW_CONFIG: EGLint = 0x3050;  // CONFIG_CAVEAT value
-pub const EGL_NON_CONFORMANT_CONFIG: EGLint = 0x3051;  // CONFIG_CAVEAT value
-pub const EGL_TRANSPARENT_RGB: EGLint = 0x3052;  // TRANSPARENT_TYPE value
-pub const EGL_RGB_BUFFER: EGLint = 0x308E;  // COLOR_BUFFER_TYPE value
-pub const EGL_LUMINANCE_BUFFER: EGLint = 0x308F;  // COLOR_BUFFER_TYPE value
+#[deprecated(note = "use `egl::config_attrib::EGL_SLOW_CONFIG`")]
+pub const EGL_SLOW_CONFIG: EGLint = config_attrib::EGL_SLOW_CONFIG;
+#[deprecated(note = "use `egl::config_attrib::EGL_NON_CONFORMANT_CONFIG`")]
+pub const EGL_NON_CONFORMANT_CONFIG: EGLint = config_attrib::EGL_NON_CONFORMANT_CONFIG;
+#[deprecated(note = "use `egl::config_attrib::EGL_TRANSPARENT_RGB`")]
+pub const EGL_TRANSPARENT_RGB: EGLint = config_attrib::EGL_TRANSPARENT_RGB;
+#[deprecated(note = "use `egl::config_attrib::EGL_RGB_BUFFER`")]
+pub const EGL_RGB_BUFFER: EGLint = config_attrib::EGL_RGB_BUFFER;
+#[deprecated(note = "use `egl::config_attrib::EGL_LUMINANCE_BUFFER`")]
+pub const EGL_LUMINANCE_BUFFER: EGLint = config_attrib::EGL_LUMINANCE_BUFFER;
 
 // more config attribute values, for TEXTURE_FORMAT
-pub const EGL_NO_TEXTURE: EGLint = 0x305C;
-pub const EGL_TEXTURE_RGB: EGLint = 0x305D;
-pub const EGL_TEXTURE_RGBA: EGLint = 0x305E;
-pub const EGL_TEXTURE_2D: EGLint = 0x305F;
+#[deprecated(note = "use `egl::surface_attrib::EGL_NO_TEXTURE`")]
+pub const EGL_NO_TEXTURE: EGLint = surface_attrib::EGL_NO_TEXTURE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_TEXTURE_RGB`")]
+pub const EGL_TEXTURE_RGB: EGLint = surface_attrib::EGL_TEXTURE_RGB;
+#[deprecated(note = "use `egl::surface_attrib::EGL_TEXTURE_RGBA`")]
+pub const EGL_TEXTURE_RGBA: EGLint = surface_attrib::EGL_TEXTURE_RGBA;
+#[deprecated(note = "use `egl::surface_attrib::EGL_TEXTURE_2D`")]
+pub const EGL_TEXTURE_2D: EGLint = surface_attrib::EGL_TEXTURE_2D;
 
 // QueryString targets
-pub const EGL_VENDOR: EGLint = 0x3053;
-pub const EGL_VERSION: EGLint = 0x3054;
-pub const EGL_EXTENSIONS: EGLint = 0x3055;
-pub const EGL_CLIENT_APIS: EGLint = 0x308D;
+#[deprecated(note = "use `egl::query_string::EGL_VENDOR`")]
+pub const EGL_VENDOR: EGLint = query_string::EGL_VENDOR;
+#[deprecated(note = "use `egl::query_string::EGL_VERSION`")]
+pub const EGL_VERSION: EGLint = query_string::EGL_VERSION;
+#[deprecated(note = "use `egl::query_string::EGL_EXTENSIONS`")]
+pub const EGL_EXTENSIONS: EGLint = query_string::EGL_EXTENSIONS;
+#[deprecated(note = "use `egl::query_string::EGL_CLIENT_APIS`")]
+pub const EGL_CLIENT_APIS: EGLint = query_string::EGL_CLIENT_APIS;
 
 // QuerySurface / SurfaceAttrib / CreatePbufferSurface targets
-pub const EGL_HEIGHT: EGLint = 0x3056;
-pub const EGL_WIDTH: EGLint = 0x3057;
-pub const EGL_LARGEST_PBUFFER: EGLint = 0x3058;
-pub const EGL_TEXTURE_FORMAT: EGLint = 0x3080;
-pub const EGL_TEXTURE_TARGET: EGLint = 0x3081;
-pub const EGL_MIPMAP_TEXTURE: EGLint = 0x3082;
-pub const EGL_MIPMAP_LEVEL: EGLint = 0x3083;
-pub const EGL_RENDER_BUFFER: EGLint = 0x3086;
-pub const EGL_VG_COLORSPACE: EGLint = 0x3087;
-pub const EGL_VG_ALPHA_FORMAT: EGLint = 0x3088;
-pub const EGL_HORIZONTAL_RESOLUTION: EGLint = 0x3090;
-pub const EGL_VERTICAL_RESOLUTION: EGLint = 0x3091;
-pub const EGL_PIXEL_ASPECT_RATIO: EGLint = 0x3092;
-pub const EGL_SWAP_BEHAVIOR: EGLint = 0x3093;
-pub const EGL_MULTISAMPLE_RESOLVE: EGLint = 0x3099;
+#[deprecated(note = "use `egl::surface_attrib::EGL_HEIGHT`")]
+pub const EGL_HEIGHT: EGLint = surface_attrib::EGL_HEIGHT;
+#[deprecated(note = "use `egl::surface_attrib::EGL_WIDTH`")]
+pub const EGL_WIDTH: EGLint = surface_attrib::EGL_WIDTH;
+#[deprecated(note = "use `egl::surface_attrib::EGL_LARGEST_PBUFFER`")]
+pub const EGL_LARGEST_PBUFFER: EGLint = surface_attrib::EGL_LARGEST_PBUFFER;
+#[deprecated(note = "use `egl::surface_attrib::EGL_TEXTURE_FORMAT`")]
+pub const EGL_TEXTURE_FORMAT: EGLint = surface_attrib::EGL_TEXTURE_FORMAT;
+#[deprecated(note = "use `egl::surface_attrib::EGL_TEXTURE_TARGET`")]
+pub const EGL_TEXTURE_TARGET: EGLint = surface_attrib::EGL_TEXTURE_TARGET;
+#[deprecated(note = "use `egl::surface_attrib::EGL_MIPMAP_TEXTURE`")]
+pub const EGL_MIPMAP_TEXTURE: EGLint = surface_attrib::EGL_MIPMAP_TEXTURE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_MIPMAP_LEVEL`")]
+pub const EGL_MIPMAP_LEVEL: EGLint = surface_attrib::EGL_MIPMAP_LEVEL;
+#[deprecated(note = "use `egl::surface_attrib::EGL_RENDER_BUFFER`")]
+pub const EGL_RENDER_BUFFER: EGLint = surface_attrib::EGL_RENDER_BUFFER;
+#[deprecated(note = "use `egl::surface_attrib::EGL_VG_COLORSPACE`")]
+pub const EGL_VG_COLORSPACE: EGLint = surface_attrib::EGL_VG_COLORSPACE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_VG_ALPHA_FORMAT`")]
+pub const EGL_VG_ALPHA_FORMAT: EGLint = surface_attrib::EGL_VG_ALPHA_FORMAT;
+#[deprecated(note = "use `egl::surface_attrib::EGL_HORIZONTAL_RESOLUTION`")]
+pub const EGL_HORIZONTAL_RESOLUTION: EGLint = surface_attrib::EGL_HORIZONTAL_RESOLUTION;
+#[deprecated(note = "use `egl::surface_attrib::EGL_VERTICAL_RESOLUTION`")]
+pub const EGL_VERTICAL_RESOLUTION: EGLint = surface_attrib::EGL_VERTICAL_RESOLUTION;
+#[deprecated(note = "use `egl::surface_attrib::EGL_PIXEL_ASPECT_RATIO`")]
+pub const EGL_PIXEL_ASPECT_RATIO: EGLint = surface_attrib::EGL_PIXEL_ASPECT_RATIO;
+#[deprecated(note = "use `egl::surface_attrib::EGL_SWAP_BEHAVIOR`")]
+pub const EGL_SWAP_BEHAVIOR: EGLint = surface_attrib::EGL_SWAP_BEHAVIOR;
+#[deprecated(note = "use `egl::surface_attrib::EGL_MULTISAMPLE_RESOLVE`")]
+pub const EGL_MULTISAMPLE_RESOLVE: EGLint = surface_attrib::EGL_MULTISAMPLE_RESOLVE;
 
 // RENDER_BUFFER values / BindTexImage / ReleaseTexImage buffer targets
-pub const EGL_BACK_BUFFER: EGLint = 0x3084;
-pub const EGL_SINGLE_BUFFER: EGLint = 0x3085;
+#[deprecated(note = "use `egl::surface_attrib::EGL_BACK_BUFFER`")]
+pub const EGL_BACK_BUFFER: EGLint = surface_attrib::EGL_BACK_BUFFER;
+#[deprecated(note = "use `egl::surface_attrib::EGL_SINGLE_BUFFER`")]
+pub const EGL_SINGLE_BUFFER: EGLint = surface_attrib::EGL_SINGLE_BUFFER;
 
 // OpenVG color spaces */
-pub const EGL_VG_COLORSPACE_sRGB: EGLint = 0x3089;  // VG_COLORSPACE value
-pub const EGL_VG_COLORSPACE_LINEAR: EGLint = 0x308A;  // VG_COLORSPACE value
+#[deprecated(note = "use `egl::surface_attrib::EGL_VG_COLORSPACE_sRGB`")]
+pub const EGL_VG_COLORSPACE_sRGB: EGLint = surface_attrib::EGL_VG_COLORSPACE_sRGB;
+#[deprecated(note = "use `egl::surface_attrib::EGL_VG_COLORSPACE_LINEAR`")]
+pub const EGL_VG_COLORSPACE_LINEAR: EGLint = surface_attrib::EGL_VG_COLORSPACE_LINEAR;
 
 // OpenVG alpha formats
-pub const EGL_VG_ALPHA_FORMAT_NONPRE: EGLint = 0x308B; // ALPHA_FORMAT value
-pub const EGL_VG_ALPHA_FORMAT_PRE: EGLint = 0x308C; // ALPHA_FORMAT value
+#[deprecated(note = "use `egl::surface_attrib::EGL_VG_ALPHA_FORMAT_NONPRE`")]
+pub const EGL_VG_ALPHA_FORMAT_NONPRE: EGLint = surface_attrib::EGL_VG_ALPHA_FORMAT_NONPRE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_VG_ALPHA_FORMAT_PRE`")]
+pub const EGL_VG_ALPHA_FORMAT_PRE: EGLint = surface_attrib::EGL_VG_ALPHA_FORMAT_PRE;
 
 // constant scale factor by which fractional display resolutions & aspect ratio are scaled when
 // queried as integer values
-pub const EGL_DISPLAY_SCALING: EGLint = 10000;
+#[deprecated(note = "use `egl::surface_attrib::EGL_DISPLAY_SCALING`")]
+pub const EGL_DISPLAY_SCALING: EGLint = surface_attrib::EGL_DISPLAY_SCALING;
 
 // unknown display resolution/aspect ratio
-pub const EGL_UNKNOWN: EGLint = -1;
+#[deprecated(note = "use `egl::surface_attrib::EGL_UNKNOWN`")]
+pub const EGL_UNKNOWN: EGLint = surface_attrib::EGL_UNKNOWN;
 
 // back buffer swap behaviors
-pub const EGL_BUFFER_PRESERVED: EGLint = 0x3094; // SWAP_BEHAVIOR value
-pub const EGL_BUFFER_DESTROYED: EGLint = 0x3095; // SWAP_BEHAVIOR value
+#[deprecated(note = "use `egl::surface_attrib::EGL_BUFFER_PRESERVED`")]
+pub const EGL_BUFFER_PRESERVED: EGLint = surface_attrib::EGL_BUFFER_PRESERVED;
+#[deprecated(note = "use `egl::surface_attrib::EGL_BUFFER_DESTROYED`")]
+pub const EGL_BUFFER_DESTROYED: EGLint = surface_attrib::EGL_BUFFER_DESTROYED;
 
 // CreatePbufferFromClientBuffer buffer types
 pub const EGL_OPENVG_IMAGE: EGLint = 0x3096;
 
 // QueryContext targets
-pub const EGL_CONTEXT_CLIENT_TYPE: EGLint = 0x3097;
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_CLIENT_TYPE`")]
+pub const EGL_CONTEXT_CLIENT_TYPE: EGLint = context_attrib::EGL_CONTEXT_CLIENT_TYPE;
 
 // CreateContext attributes
-pub const EGL_CONTEXT_CLIENT_VERSION: EGLint = 0x3098;
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_CLIENT_VERSION`")]
+pub const EGL_CONTEXT_CLIENT_VERSION: EGLint = context_attrib::EGL_CONTEXT_CLIENT_VERSION;
 
 // multisample resolution behaviors
-pub const EGL_MULTISAMPLE_RESOLVE_DEFAULT: EGLint = 0x309A; // MULTISAMPLE_RESOLVE value
-pub const EGL_MULTISAMPLE_RESOLVE_BOX: EGLint = 0x309B; // MULTISAMPLE_RESOLVE value
+#[deprecated(note = "use `egl::surface_attrib::EGL_MULTISAMPLE_RESOLVE_DEFAULT`")]
+pub const EGL_MULTISAMPLE_RESOLVE_DEFAULT: EGLint = surface_attrib::EGL_MULTISAMPLE_RESOLVE_DEFAULT;
+#[deprecated(note = "use `egl::surface_attrib::EGL_MULTISAMPLE_RESOLVE_BOX`")]
+pub const EGL_MULTISAMPLE_RESOLVE_BOX: EGLint = surface_attrib::EGL_MULTISAMPLE_RESOLVE_BOX;
 
 // BindAPI/QueryAPI targets
-pub const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
-pub const EGL_OPENVG_API: EGLenum = 0x30A1;
-pub const EGL_OPENGL_API: EGLenum = 0x30A2;
+#[deprecated(note = "use `egl::api::EGL_OPENGL_ES_API`")]
+pub const EGL_OPENGL_ES_API: EGLenum = api::EGL_OPENGL_ES_API;
+#[deprecated(note = "use `egl::api::EGL_OPENVG_API`")]
+pub const EGL_OPENVG_API: EGLenum = api::EGL_OPENVG_API;
+#[deprecated(note = "use `egl::api::EGL_OPENGL_API`")]
+pub const EGL_OPENGL_API: EGLenum = api::EGL_OPENGL_API;
 
 // GetCurrentSurface targets
 pub const EGL_DRAW: EGLint = 0x3059;
@@ -252,100 +449,654 @@ pub const EGL_READ: EGLint = 0x305A;
 pub const EGL_CORE_NATIVE_ENGINE: EGLint = 0x305B;
 
 // EGL 1.2 tokens renamed for consistency in EGL 1.3
-pub const EGL_COLORSPACE: EGLint = EGL_VG_COLORSPACE;
-pub const EGL_ALPHA_FORMAT: EGLint = EGL_VG_ALPHA_FORMAT;
-pub const EGL_COLORSPACE_sRGB: EGLint = EGL_VG_COLORSPACE_sRGB;
-pub const EGL_COLORSPACE_LINEAR: EGLint = EGL_VG_COLORSPACE_LINEAR;
-pub const EGL_ALPHA_FORMAT_NONPRE: EGLint = EGL_VG_ALPHA_FORMAT_NONPRE;
-pub const EGL_ALPHA_FORMAT_PRE: EGLint = EGL_VG_ALPHA_FORMAT_PRE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_COLORSPACE`")]
+pub const EGL_COLORSPACE: EGLint = surface_attrib::EGL_COLORSPACE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_ALPHA_FORMAT`")]
+pub const EGL_ALPHA_FORMAT: EGLint = surface_attrib::EGL_ALPHA_FORMAT;
+#[deprecated(note = "use `egl::surface_attrib::EGL_COLORSPACE_sRGB`")]
+pub const EGL_COLORSPACE_sRGB: EGLint = surface_attrib::EGL_COLORSPACE_sRGB;
+#[deprecated(note = "use `egl::surface_attrib::EGL_COLORSPACE_LINEAR`")]
+pub const EGL_COLORSPACE_LINEAR: EGLint = surface_attrib::EGL_COLORSPACE_LINEAR;
+#[deprecated(note = "use `egl::surface_attrib::EGL_ALPHA_FORMAT_NONPRE`")]
+pub const EGL_ALPHA_FORMAT_NONPRE: EGLint = surface_attrib::EGL_ALPHA_FORMAT_NONPRE;
+#[deprecated(note = "use `egl::surface_attrib::EGL_ALPHA_FORMAT_PRE`")]
+pub const EGL_ALPHA_FORMAT_PRE: EGLint = surface_attrib::EGL_ALPHA_FORMAT_PRE;
 
 // EGL 1.5
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: EGLint = 0x31BD;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_NO_RESET_NOTIFICATION: EGLint = 0x31BE;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_LOSE_CONTEXT_ON_RESET: EGLint = 0x31BF;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: EGLint = 0x00000002;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE: EGLint = 0x31B1;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONTEXT_OPENGL_ROBUST_ACCESS: EGLint = 0x31B2;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_OPENGL_ES3_BIT: EGLint = 0x00000040;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CL_EVENT_HANDLE: EGLint = 0x309C;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_CL_EVENT: EGLint = 0x30FE;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_CL_EVENT_COMPLETE: EGLint = 0x30FF;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_PRIOR_COMMANDS_COMPLETE: EGLint = 0x30F0;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_TYPE: EGLint = 0x30F7;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_STATUS: EGLint = 0x30F1;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_CONDITION: EGLint = 0x30F8;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SIGNALED: EGLint = 0x30F2;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_UNSIGNALED: EGLint = 0x30F3;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_FLUSH_COMMANDS_BIT: EGLint = 0x0001;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_FOREVER: u64 = 0xFFFFFFFFFFFFFFFF;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_TIMEOUT_EXPIRED: EGLint = 0x30F5;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_CONDITION_SATISFIED: EGLint = 0x30F6;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_NO_SYNC: EGLSync = 0 as EGLSync;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_SYNC_FENCE: EGLint = 0x30F9;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_COLORSPACE: EGLint = 0x309D;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_COLORSPACE_SRGB: EGLint = 0x3089;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_COLORSPACE_LINEAR: EGLint = 0x308A;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_RENDERBUFFER: EGLint = 0x30B9;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_2D: EGLint = 0x30B1;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_LEVEL: EGLint = 0x30BC;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_3D: EGLint = 0x30B2;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_ZOFFSET: EGLint = 0x30BD;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_X: EGLint = 0x30B3;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_X: EGLint = 0x30B4;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Y: EGLint = 0x30B5;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Y: EGLint = 0x30B6;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Z: EGLint = 0x30B7;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Z: EGLint = 0x30B8;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_IMAGE_PRESERVED: EGLint = 0x30D2;
-#[cfg(feature = "egl_1_5")]
-pub const EGL_NO_IMAGE: EGLImage = 0 as EGLImage;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_MAJOR_VERSION`")]
+pub const EGL_CONTEXT_MAJOR_VERSION: EGLint = context_attrib::EGL_CONTEXT_MAJOR_VERSION;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_MINOR_VERSION`")]
+pub const EGL_CONTEXT_MINOR_VERSION: EGLint = context_attrib::EGL_CONTEXT_MINOR_VERSION;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_PROFILE_MASK`")]
+pub const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = context_attrib::EGL_CONTEXT_OPENGL_PROFILE_MASK;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY`")]
+pub const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: EGLint =
+    context_attrib::EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_NO_RESET_NOTIFICATION`")]
+pub const EGL_NO_RESET_NOTIFICATION: EGLint = context_attrib::EGL_NO_RESET_NOTIFICATION;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_LOSE_CONTEXT_ON_RESET`")]
+pub const EGL_LOSE_CONTEXT_ON_RESET: EGLint = context_attrib::EGL_LOSE_CONTEXT_ON_RESET;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT`")]
+pub const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint =
+    context_attrib::EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT`")]
+pub const EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: EGLint =
+    context_attrib::EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_DEBUG`")]
+pub const EGL_CONTEXT_OPENGL_DEBUG: EGLint = context_attrib::EGL_CONTEXT_OPENGL_DEBUG;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE`")]
+pub const EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE: EGLint =
+    context_attrib::EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::context_attrib::EGL_CONTEXT_OPENGL_ROBUST_ACCESS`")]
+pub const EGL_CONTEXT_OPENGL_ROBUST_ACCESS: EGLint =
+    context_attrib::EGL_CONTEXT_OPENGL_ROBUST_ACCESS;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::config_attrib::EGL_OPENGL_ES3_BIT`")]
+pub const EGL_OPENGL_ES3_BIT: EGLint = config_attrib::EGL_OPENGL_ES3_BIT;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_CL_EVENT_HANDLE`")]
+pub const EGL_CL_EVENT_HANDLE: EGLint = sync::EGL_CL_EVENT_HANDLE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_CL_EVENT`")]
+pub const EGL_SYNC_CL_EVENT: EGLint = sync::EGL_SYNC_CL_EVENT;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_CL_EVENT_COMPLETE`")]
+pub const EGL_SYNC_CL_EVENT_COMPLETE: EGLint = sync::EGL_SYNC_CL_EVENT_COMPLETE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_PRIOR_COMMANDS_COMPLETE`")]
+pub const EGL_SYNC_PRIOR_COMMANDS_COMPLETE: EGLint = sync::EGL_SYNC_PRIOR_COMMANDS_COMPLETE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_TYPE`")]
+pub const EGL_SYNC_TYPE: EGLint = sync::EGL_SYNC_TYPE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_STATUS`")]
+pub const EGL_SYNC_STATUS: EGLint = sync::EGL_SYNC_STATUS;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_CONDITION`")]
+pub const EGL_SYNC_CONDITION: EGLint = sync::EGL_SYNC_CONDITION;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SIGNALED`")]
+pub const EGL_SIGNALED: EGLint = sync::EGL_SIGNALED;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_UNSIGNALED`")]
+pub const EGL_UNSIGNALED: EGLint = sync::EGL_UNSIGNALED;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_FLUSH_COMMANDS_BIT`")]
+pub const EGL_SYNC_FLUSH_COMMANDS_BIT: EGLint = sync::EGL_SYNC_FLUSH_COMMANDS_BIT;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_FOREVER`")]
+pub const EGL_FOREVER: u64 = sync::EGL_FOREVER;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_TIMEOUT_EXPIRED`")]
+pub const EGL_TIMEOUT_EXPIRED: EGLint = sync::EGL_TIMEOUT_EXPIRED;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_CONDITION_SATISFIED`")]
+pub const EGL_CONDITION_SATISFIED: EGLint = sync::EGL_CONDITION_SATISFIED;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_NO_SYNC`")]
+pub const EGL_NO_SYNC: EGLSync = sync::EGL_NO_SYNC;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::sync::EGL_SYNC_FENCE`")]
+pub const EGL_SYNC_FENCE: EGLint = sync::EGL_SYNC_FENCE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::surface_attrib::EGL_GL_COLORSPACE`")]
+pub const EGL_GL_COLORSPACE: EGLint = surface_attrib::EGL_GL_COLORSPACE;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::surface_attrib::EGL_GL_COLORSPACE_SRGB`")]
+pub const EGL_GL_COLORSPACE_SRGB: EGLint = surface_attrib::EGL_GL_COLORSPACE_SRGB;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::surface_attrib::EGL_GL_COLORSPACE_LINEAR`")]
+pub const EGL_GL_COLORSPACE_LINEAR: EGLint = surface_attrib::EGL_GL_COLORSPACE_LINEAR;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_RENDERBUFFER`")]
+pub const EGL_GL_RENDERBUFFER: EGLint = image::EGL_GL_RENDERBUFFER;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_2D`")]
+pub const EGL_GL_TEXTURE_2D: EGLint = image::EGL_GL_TEXTURE_2D;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_LEVEL`")]
+pub const EGL_GL_TEXTURE_LEVEL: EGLint = image::EGL_GL_TEXTURE_LEVEL;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_3D`")]
+pub const EGL_GL_TEXTURE_3D: EGLint = image::EGL_GL_TEXTURE_3D;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_ZOFFSET`")]
+pub const EGL_GL_TEXTURE_ZOFFSET: EGLint = image::EGL_GL_TEXTURE_ZOFFSET;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_X`")]
+pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_X: EGLint = image::EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_X;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_X`")]
+pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_X: EGLint = image::EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_X;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Y`")]
+pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Y: EGLint = image::EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Y;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Y`")]
+pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Y: EGLint = image::EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Y;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Z`")]
+pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Z: EGLint = image::EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Z;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Z`")]
+pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Z: EGLint = image::EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Z;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_IMAGE_PRESERVED`")]
+pub const EGL_IMAGE_PRESERVED: EGLint = image::EGL_IMAGE_PRESERVED;
+#[cfg(egl_has_1_5)]
+#[deprecated(note = "use `egl::image::EGL_NO_IMAGE`")]
+pub const EGL_NO_IMAGE: EGLImage = image::EGL_NO_IMAGE;
+
+// -------------------------------------------------------------------------------------------------
+// CONSTANT GROUPS
+// -------------------------------------------------------------------------------------------------
+
+// Constants grouped by their use. Flat names of the constants that were available before
+// grouping are kept above as deprecated aliases.
+
+/// Values returned by `eglGetError`.
+pub mod error_code {
+    use super::EGLint;
+
+    pub const EGL_SUCCESS: EGLint = 0x3000;
+    pub const EGL_NOT_INITIALIZED: EGLint = 0x3001;
+    pub const EGL_BAD_ACCESS: EGLint = 0x3002;
+    pub const EGL_BAD_ALLOC: EGLint = 0x3003;
+    pub const EGL_BAD_ATTRIBUTE: EGLint = 0x3004;
+    pub const EGL_BAD_CONFIG: EGLint = 0x3005;
+    pub const EGL_BAD_CONTEXT: EGLint = 0x3006;
+    pub const EGL_BAD_CURRENT_SURFACE: EGLint = 0x3007;
+    pub const EGL_BAD_DISPLAY: EGLint = 0x3008;
+    pub const EGL_BAD_MATCH: EGLint = 0x3009;
+    pub const EGL_BAD_NATIVE_PIXMAP: EGLint = 0x300A;
+    pub const EGL_BAD_NATIVE_WINDOW: EGLint = 0x300B;
+    pub const EGL_BAD_PARAMETER: EGLint = 0x300C;
+    pub const EGL_BAD_SURFACE: EGLint = 0x300D;
+    pub const EGL_CONTEXT_LOST: EGLint = 0x300E;  // EGL 1.1 - IMG_power_management
+
+    pub const EGL_BAD_STREAM_KHR: EGLint = 0x321B;  // EGL_KHR_stream
+    pub const EGL_BAD_STATE_KHR: EGLint = 0x321C;  // EGL_KHR_stream
+    pub const EGL_BAD_DEVICE_EXT: EGLint = 0x322B;  // EGL_EXT_device_base
+}
+
+/// Config attributes and their values, for `eglChooseConfig` and `eglGetConfigAttrib`.
+pub mod config_attrib {
+    use super::EGLint;
+
+    pub const EGL_BUFFER_SIZE: EGLint = 0x3020;
+    pub const EGL_ALPHA_SIZE: EGLint = 0x3021;
+    pub const EGL_BLUE_SIZE: EGLint = 0x3022;
+    pub const EGL_GREEN_SIZE: EGLint = 0x3023;
+    pub const EGL_RED_SIZE: EGLint = 0x3024;
+    pub const EGL_DEPTH_SIZE: EGLint = 0x3025;
+    pub const EGL_STENCIL_SIZE: EGLint = 0x3026;
+    pub const EGL_CONFIG_CAVEAT: EGLint = 0x3027;
+    pub const EGL_CONFIG_ID: EGLint = 0x3028;
+    pub const EGL_LEVEL: EGLint = 0x3029;
+    pub const EGL_MAX_PBUFFER_HEIGHT: EGLint = 0x302A;
+    pub const EGL_MAX_PBUFFER_PIXELS: EGLint = 0x302B;
+    pub const EGL_MAX_PBUFFER_WIDTH: EGLint = 0x302C;
+    pub const EGL_NATIVE_RENDERABLE: EGLint = 0x302D;
+    pub const EGL_NATIVE_VISUAL_ID: EGLint = 0x302E;
+    pub const EGL_NATIVE_VISUAL_TYPE: EGLint = 0x302F;
+    pub const EGL_SAMPLES: EGLint = 0x3031;
+    pub const EGL_SAMPLE_BUFFERS: EGLint = 0x3032;
+    pub const EGL_SURFACE_TYPE: EGLint = 0x3033;
+    pub const EGL_TRANSPARENT_TYPE: EGLint = 0x3034;
+    pub const EGL_TRANSPARENT_BLUE_VALUE: EGLint = 0x3035;
+    pub const EGL_TRANSPARENT_GREEN_VALUE: EGLint = 0x3036;
+    pub const EGL_TRANSPARENT_RED_VALUE: EGLint = 0x3037;
+    pub const EGL_NONE: EGLint = 0x3038; // attrib list terminator
+    pub const EGL_BIND_TO_TEXTURE_RGB: EGLint = 0x3039;
+    pub const EGL_BIND_TO_TEXTURE_RGBA: EGLint = 0x303A;
+    pub const EGL_MIN_SWAP_INTERVAL: EGLint = 0x303B;
+    pub const EGL_MAX_SWAP_INTERVAL: EGLint = 0x303C;
+    pub const EGL_LUMINANCE_SIZE: EGLint = 0x303D;
+    pub const EGL_ALPHA_MASK_SIZE: EGLint = 0x303E;
+    pub const EGL_COLOR_BUFFER_TYPE: EGLint = 0x303F;
+    pub const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+    pub const EGL_MATCH_NATIVE_PIXMAP: EGLint = 0x3041;  // psseudo-attribute (not queryable)
+    pub const EGL_CONFORMANT: EGLint = 0x3042;
+    pub const EGL_DONT_CARE: EGLint = -1;
+
+    pub const EGL_SLOW_CONFIG: EGLint = 0x3050;  // CONFIG_CAVEAT value
+    pub const EGL_NON_CONFORMANT_CONFIG: EGLint = 0x3051;  // CONFIG_CAVEAT value
+    pub const EGL_TRANSPARENT_RGB: EGLint = 0x3052;  // TRANSPARENT_TYPE value
+    pub const EGL_RGB_BUFFER: EGLint = 0x308E;  // COLOR_BUFFER_TYPE value
+    pub const EGL_LUMINANCE_BUFFER: EGLint = 0x308F;  // COLOR_BUFFER_TYPE value
+    pub use super::surface_attrib::EGL_NO_TEXTURE;
+    pub use super::surface_attrib::EGL_TEXTURE_RGB;
+    pub use super::surface_attrib::EGL_TEXTURE_RGBA;
+    pub use super::surface_attrib::EGL_TEXTURE_2D;
+
+    pub const EGL_CONFIG_SELECT_GROUP_EXT: EGLint = 0x34C0;
+
+    #[cfg(egl_has_1_5)]
+    pub const EGL_OPENGL_ES3_BIT: EGLint = 0x00000040;
+}
+
+/// Surface attributes and their values, for `eglCreate*Surface`, `eglQuerySurface`
+/// and `eglSurfaceAttrib`.
+pub mod surface_attrib {
+    use super::EGLint;
+
+    pub const EGL_HEIGHT: EGLint = 0x3056;
+    pub const EGL_WIDTH: EGLint = 0x3057;
+    pub const EGL_LARGEST_PBUFFER: EGLint = 0x3058;
+    pub const EGL_TEXTURE_FORMAT: EGLint = 0x3080;
+    pub const EGL_TEXTURE_TARGET: EGLint = 0x3081;
+    pub const EGL_MIPMAP_TEXTURE: EGLint = 0x3082;
+    pub const EGL_MIPMAP_LEVEL: EGLint = 0x3083;
+    pub const EGL_RENDER_BUFFER: EGLint = 0x3086;
+    pub const EGL_VG_COLORSPACE: EGLint = 0x3087;
+    pub const EGL_VG_ALPHA_FORMAT: EGLint = 0x3088;
+    pub const EGL_HORIZONTAL_RESOLUTION: EGLint = 0x3090;
+    pub const EGL_VERTICAL_RESOLUTION: EGLint = 0x3091;
+    pub const EGL_PIXEL_ASPECT_RATIO: EGLint = 0x3092;
+    pub const EGL_SWAP_BEHAVIOR: EGLint = 0x3093;
+    pub const EGL_MULTISAMPLE_RESOLVE: EGLint = 0x3099;
+    pub const EGL_COLORSPACE: EGLint = EGL_VG_COLORSPACE;
+    pub const EGL_ALPHA_FORMAT: EGLint = EGL_VG_ALPHA_FORMAT;
+
+    pub const EGL_BACK_BUFFER: EGLint = 0x3084;
+    pub const EGL_SINGLE_BUFFER: EGLint = 0x3085;
+    pub const EGL_VG_COLORSPACE_sRGB: EGLint = 0x3089;  // VG_COLORSPACE value
+    pub const EGL_VG_COLORSPACE_LINEAR: EGLint = 0x308A;  // VG_COLORSPACE value
+    pub const EGL_VG_ALPHA_FORMAT_NONPRE: EGLint = 0x308B; // ALPHA_FORMAT value
+    pub const EGL_VG_ALPHA_FORMAT_PRE: EGLint = 0x308C; // ALPHA_FORMAT value
+    pub const EGL_COLORSPACE_sRGB: EGLint = EGL_VG_COLORSPACE_sRGB;
+    pub const EGL_COLORSPACE_LINEAR: EGLint = EGL_VG_COLORSPACE_LINEAR;
+    pub const EGL_ALPHA_FORMAT_NONPRE: EGLint = EGL_VG_ALPHA_FORMAT_NONPRE;
+    pub const EGL_ALPHA_FORMAT_PRE: EGLint = EGL_VG_ALPHA_FORMAT_PRE;
+    pub const EGL_DISPLAY_SCALING: EGLint = 10000;
+    pub const EGL_UNKNOWN: EGLint = -1;
+    pub const EGL_BUFFER_PRESERVED: EGLint = 0x3094; // SWAP_BEHAVIOR value
+    pub const EGL_BUFFER_DESTROYED: EGLint = 0x3095; // SWAP_BEHAVIOR value
+    pub const EGL_MULTISAMPLE_RESOLVE_DEFAULT: EGLint = 0x309A; // MULTISAMPLE_RESOLVE value
+    pub const EGL_MULTISAMPLE_RESOLVE_BOX: EGLint = 0x309B; // MULTISAMPLE_RESOLVE value
+    pub const EGL_NO_TEXTURE: EGLint = 0x305C;
+    pub const EGL_TEXTURE_RGB: EGLint = 0x305D;
+    pub const EGL_TEXTURE_RGBA: EGLint = 0x305E;
+    pub const EGL_TEXTURE_2D: EGLint = 0x305F;
+
+    #[cfg(egl_has_1_5)]
+    pub const EGL_GL_COLORSPACE: EGLint = 0x309D;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_GL_COLORSPACE_SRGB: EGLint = 0x3089;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_GL_COLORSPACE_LINEAR: EGLint = 0x308A;
+}
+
+/// Context attributes and their values, for `eglCreateContext` and `eglQueryContext`.
+pub mod context_attrib {
+    use super::EGLint;
+
+    pub const EGL_CONTEXT_CLIENT_TYPE: EGLint = 0x3097;
+    pub const EGL_CONTEXT_CLIENT_VERSION: EGLint = 0x3098;
+
+    pub const EGL_CONTEXT_PRIORITY_LEVEL_IMG: EGLint = 0x3100;
+    pub const EGL_CONTEXT_PRIORITY_HIGH_IMG: EGLint = 0x3101;
+    pub const EGL_CONTEXT_PRIORITY_MEDIUM_IMG: EGLint = 0x3102;
+    pub const EGL_CONTEXT_PRIORITY_LOW_IMG: EGLint = 0x3103;
+
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: EGLint = 0x31BD;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_NO_RESET_NOTIFICATION: EGLint = 0x31BE;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_LOSE_CONTEXT_ON_RESET: EGLint = 0x31BF;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x00000001;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: EGLint = 0x00000002;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE: EGLint = 0x31B1;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONTEXT_OPENGL_ROBUST_ACCESS: EGLint = 0x31B2;
+}
+
+/// Linux dmabuf image target and attributes, for `eglCreateImage`.
+pub mod dmabuf {
+    use super::{EGLenum, EGLint};
+
+    pub const EGL_LINUX_DMA_BUF_EXT: EGLenum = 0x3270;
+    pub const EGL_LINUX_DRM_FOURCC_EXT: EGLint = 0x3271;
+    pub const EGL_DMA_BUF_PLANE0_FD_EXT: EGLint = 0x3272;
+    pub const EGL_DMA_BUF_PLANE0_OFFSET_EXT: EGLint = 0x3273;
+    pub const EGL_DMA_BUF_PLANE0_PITCH_EXT: EGLint = 0x3274;
+    pub const EGL_DMA_BUF_PLANE0_MODIFIER_LO_EXT: EGLint = 0x3443;
+    pub const EGL_DMA_BUF_PLANE0_MODIFIER_HI_EXT: EGLint = 0x3444;
+}
+
+/// Platforms and platform display attributes, for `eglGetPlatformDisplay`.
+pub mod platform {
+    use super::{EGLenum, EGLint};
+
+    pub const EGL_PLATFORM_X11_EXT: EGLenum = 0x31D5;
+    pub const EGL_PLATFORM_X11_SCREEN_EXT: EGLint = 0x31D6;
+    pub const EGL_PLATFORM_GBM_KHR: EGLenum = 0x31D7;
+    pub const EGL_PLATFORM_WAYLAND_KHR: EGLenum = 0x31D8;
+    pub const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+    pub const EGL_PLATFORM_DEVICE_EXT: EGLenum = 0x313F;
+}
+
+/// Object types and message types, for `eglLabelObjectKHR` and
+/// `eglDebugMessageControlKHR`.
+pub mod debug {
+    use super::{EGLenum, EGLint};
+
+    pub const EGL_OBJECT_THREAD_KHR: EGLenum = 0x33B0;
+    pub const EGL_OBJECT_DISPLAY_KHR: EGLenum = 0x33B1;
+    pub const EGL_OBJECT_CONTEXT_KHR: EGLenum = 0x33B2;
+    pub const EGL_OBJECT_SURFACE_KHR: EGLenum = 0x33B3;
+    pub const EGL_OBJECT_IMAGE_KHR: EGLenum = 0x33B4;
+    pub const EGL_OBJECT_SYNC_KHR: EGLenum = 0x33B5;
+    pub const EGL_OBJECT_STREAM_KHR: EGLenum = 0x33B6;
+    pub const EGL_DEBUG_CALLBACK_KHR: EGLint = 0x33B8;
+    pub const EGL_DEBUG_MSG_CRITICAL_KHR: EGLint = 0x33B9;
+    pub const EGL_DEBUG_MSG_ERROR_KHR: EGLint = 0x33BA;
+    pub const EGL_DEBUG_MSG_WARN_KHR: EGLint = 0x33BB;
+    pub const EGL_DEBUG_MSG_INFO_KHR: EGLint = 0x33BC;
+}
+
+/// Client APIs, for `eglBindAPI` and `eglQueryAPI`.
+pub mod api {
+    use super::EGLenum;
+
+    pub const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+    pub const EGL_OPENVG_API: EGLenum = 0x30A1;
+    pub const EGL_OPENGL_API: EGLenum = 0x30A2;
+}
+
+/// Names for `eglQueryString`.
+pub mod query_string {
+    use super::EGLint;
+
+    pub const EGL_VENDOR: EGLint = 0x3053;
+    pub const EGL_VERSION: EGLint = 0x3054;
+    pub const EGL_EXTENSIONS: EGLint = 0x3055;
+    pub const EGL_CLIENT_APIS: EGLint = 0x308D;
+}
+
+/// Sync object types, attributes and values, for `eglCreateSync` and
+/// `eglClientWaitSync`, and their `EGL_KHR_fence_sync` equivalents.
+pub mod sync {
+    use super::{EGLSyncKHR, EGLTimeKHR, EGLint};
+    #[cfg(egl_has_1_5)]
+    use super::EGLSync;
+
+    pub const EGL_SYNC_PRIOR_COMMANDS_COMPLETE_KHR: EGLint = 0x30F0;
+    pub const EGL_SYNC_STATUS_KHR: EGLint = 0x30F1;
+    pub const EGL_SIGNALED_KHR: EGLint = 0x30F2;
+    pub const EGL_UNSIGNALED_KHR: EGLint = 0x30F3;
+    pub const EGL_TIMEOUT_EXPIRED_KHR: EGLint = 0x30F5;
+    pub const EGL_CONDITION_SATISFIED_KHR: EGLint = 0x30F6;
+    pub const EGL_SYNC_TYPE_KHR: EGLint = 0x30F7;
+    pub const EGL_SYNC_CONDITION_KHR: EGLint = 0x30F8;
+    pub const EGL_SYNC_FENCE_KHR: EGLint = 0x30F9;
+    pub const EGL_SYNC_FLUSH_COMMANDS_BIT_KHR: EGLint = 0x0001;
+    pub const EGL_FOREVER_KHR: EGLTimeKHR = 0xFFFFFFFFFFFFFFFF;
+    pub const EGL_NO_SYNC_KHR: EGLSyncKHR = 0 as EGLSyncKHR;
+
+    pub const EGL_SYNC_NATIVE_FENCE_ANDROID: EGLint = 0x3144;
+    pub const EGL_SYNC_NATIVE_FENCE_FD_ANDROID: EGLint = 0x3145;
+    pub const EGL_SYNC_NATIVE_FENCE_SIGNALED_ANDROID: EGLint = 0x3146;
+    pub const EGL_NO_NATIVE_FENCE_FD_ANDROID: EGLint = -1;
+
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CL_EVENT_HANDLE: EGLint = 0x309C;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_CL_EVENT: EGLint = 0x30FE;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_CL_EVENT_COMPLETE: EGLint = 0x30FF;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_PRIOR_COMMANDS_COMPLETE: EGLint = 0x30F0;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_TYPE: EGLint = 0x30F7;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_STATUS: EGLint = 0x30F1;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_CONDITION: EGLint = 0x30F8;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SIGNALED: EGLint = 0x30F2;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_UNSIGNALED: EGLint = 0x30F3;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_FLUSH_COMMANDS_BIT: EGLint = 0x0001;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_FOREVER: u64 = 0xFFFFFFFFFFFFFFFF;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_TIMEOUT_EXPIRED: EGLint = 0x30F5;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_CONDITION_SATISFIED: EGLint = 0x30F6;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_NO_SYNC: EGLSync = 0 as EGLSync;
+    #[cfg(egl_has_1_5)]
+    pub const EGL_SYNC_FENCE: EGLint = 0x30F9;
+}
+
+/// `[EGL_ANDROID_get_frame_timestamps]` Surface attribute and timestamp names, for
+/// `eglGetFrameTimestampsANDROID`.
+pub mod frame_timestamps {
+    use super::{EGLint, EGLnsecsANDROID};
+
+    pub const EGL_TIMESTAMPS_ANDROID: EGLint = 0x3430;
+    pub const EGL_COMPOSITE_DEADLINE_ANDROID: EGLint = 0x3431;
+    pub const EGL_COMPOSITE_INTERVAL_ANDROID: EGLint = 0x3432;
+    pub const EGL_COMPOSITE_TO_PRESENT_LATENCY_ANDROID: EGLint = 0x3433;
+    pub const EGL_REQUESTED_PRESENT_TIME_ANDROID: EGLint = 0x3434;
+    pub const EGL_RENDERING_COMPLETE_TIME_ANDROID: EGLint = 0x3435;
+    pub const EGL_COMPOSITION_LATCH_TIME_ANDROID: EGLint = 0x3436;
+    pub const EGL_FIRST_COMPOSITION_START_TIME_ANDROID: EGLint = 0x3437;
+    pub const EGL_LAST_COMPOSITION_START_TIME_ANDROID: EGLint = 0x3438;
+    pub const EGL_FIRST_COMPOSITION_GPU_FINISHED_TIME_ANDROID: EGLint = 0x3439;
+    pub const EGL_DISPLAY_PRESENT_TIME_ANDROID: EGLint = 0x343A;
+    pub const EGL_DEQUEUE_READY_TIME_ANDROID: EGLint = 0x343B;
+    pub const EGL_READS_DONE_TIME_ANDROID: EGLint = 0x343C;
+    pub const EGL_TIMESTAMP_PENDING_ANDROID: EGLnsecsANDROID = -2;
+    pub const EGL_TIMESTAMP_INVALID_ANDROID: EGLnsecsANDROID = -1;
+}
+
+/// `[EGL_KHR_stream]` Stream attributes and states, for `eglQueryStreamKHR`.
+#[cfg(feature = "egl_stream")]
+pub mod stream {
+    use super::{EGLenum, EGLint, EGLStreamKHR};
+
+    pub const EGL_NO_STREAM_KHR: EGLStreamKHR = 0 as EGLStreamKHR;
+    pub const EGL_CONSUMER_LATENCY_USEC_KHR: EGLenum = 0x3210;
+    pub const EGL_PRODUCER_FRAME_KHR: EGLenum = 0x3212;
+    pub const EGL_CONSUMER_FRAME_KHR: EGLenum = 0x3213;
+    pub const EGL_STREAM_STATE_KHR: EGLenum = 0x3214;
+    pub const EGL_STREAM_STATE_CREATED_KHR: EGLint = 0x3215;
+    pub const EGL_STREAM_STATE_CONNECTING_KHR: EGLint = 0x3216;
+    pub const EGL_STREAM_STATE_EMPTY_KHR: EGLint = 0x3217;
+    pub const EGL_STREAM_STATE_NEW_FRAME_AVAILABLE_KHR: EGLint = 0x3218;
+    pub const EGL_STREAM_STATE_OLD_FRAME_AVAILABLE_KHR: EGLint = 0x3219;
+    pub const EGL_STREAM_STATE_DISCONNECTED_KHR: EGLint = 0x321A;
+}
+
+/// `[EGL_EXT_device_base]` Device handles and attributes, for `eglQueryDisplayAttribEXT`
+/// and `eglQueryDeviceStringEXT`.
+pub mod device {
+    use super::{EGLDeviceEXT, EGLint};
+
+    pub const EGL_NO_DEVICE_EXT: EGLDeviceEXT = 0 as EGLDeviceEXT;
+    pub const EGL_DEVICE_EXT: EGLint = 0x322C;
+    pub const EGL_DRM_DEVICE_FILE_EXT: EGLint = 0x3233;
+}
+
+/// `[EGL 1.5]` Image targets and attributes, for `eglCreateImage`.
+#[cfg(egl_has_1_5)]
+pub mod image {
+    use super::{EGLImage, EGLint};
+
+    pub const EGL_GL_RENDERBUFFER: EGLint = 0x30B9;
+    pub const EGL_GL_TEXTURE_2D: EGLint = 0x30B1;
+    pub const EGL_GL_TEXTURE_LEVEL: EGLint = 0x30BC;
+    pub const EGL_GL_TEXTURE_3D: EGLint = 0x30B2;
+    pub const EGL_GL_TEXTURE_ZOFFSET: EGLint = 0x30BD;
+    pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_X: EGLint = 0x30B3;
+    pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_X: EGLint = 0x30B4;
+    pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Y: EGLint = 0x30B5;
+    pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Y: EGLint = 0x30B6;
+    pub const EGL_GL_TEXTURE_CUBE_MAP_POSITIVE_Z: EGLint = 0x30B7;
+    pub const EGL_GL_TEXTURE_CUBE_MAP_NEGATIVE_Z: EGLint = 0x30B8;
+    pub const EGL_IMAGE_PRESERVED: EGLint = 0x30D2;
+    pub const EGL_NO_IMAGE: EGLImage = 0 as EGLImage;
+}
+
+// -------------------------------------------------------------------------------------------------
+// EXTENSION LOADING
+// -------------------------------------------------------------------------------------------------
+
+/// Load an extension function with `eglGetProcAddress`, looking it up only on the
+/// first call from each call site.
+///
+/// Returns `Error::SymbolNotFound` if the function is not available. The function
+/// pointer type is inferred, and must match the C signature of the named function.
+macro_rules! load_extension_function {
+    ($procname:expr) => {{
+        static ADDRESS: OnceLock<usize> = OnceLock::new();
+        resolve_extension_function(&ADDRESS, $procname)
+    }};
+}
+
+// Signature of `ffi::eglGetProcAddress` with a nullable return value.
+type GetProcAddressFn = unsafe extern "C" fn(*const c_char) -> *const c_void;
+
+/// Returns the function stored in `address`, looking it up with `eglGetProcAddress`
+/// first if it was not stored yet. `F` must be the function pointer type matching
+/// the C signature of the named function.
+unsafe fn resolve_extension_function<F: Copy>(address: &OnceLock<usize>,
+                                              procname: &'static str)
+                                              -> error::Result<F> {
+    debug_assert_eq!(mem::size_of::<F>(), mem::size_of::<usize>());
+
+    let address = *address.get_or_init(|| {
+        // `ffi::eglGetProcAddress` returns a non-nullable function pointer, so it is
+        // called through a signature that allows checking for null.
+        let get_proc_address: GetProcAddressFn =
+            mem::transmute(ffi::eglGetProcAddress as
+                           unsafe extern "C" fn(*const c_char) -> extern "C" fn());
+        let string = CString::new(procname).unwrap();
+        get_proc_address(string.as_ptr()) as usize
+    });
+
+    if address == 0 {
+        return Err(error::Error::SymbolNotFound(procname));
+    }
+    Ok(mem::transmute_copy(&address))
+}
+
+// -------------------------------------------------------------------------------------------------
+// DEBUG CALLBACK
+// -------------------------------------------------------------------------------------------------
+
+/// `[EGL_KHR_debug]` Message passed to the callback set with `set_debug_callback`.
+#[cfg(feature = "egl_debug")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DebugMessage<'a> {
+    /// EGL error code, such as `EGL_BAD_SURFACE`, or `EGL_SUCCESS` for messages that
+    /// are not errors.
+    pub error: EGLenum,
+    /// Name of the EGL function that generated the message, such as `eglSwapBuffers`.
+    pub command: Option<&'a str>,
+    /// Severity of the message, such as `EGL_DEBUG_MSG_ERROR_KHR`.
+    pub message_type: EGLint,
+    /// Label of the object the message is about, if it was set with `label_object_str`.
+    pub object_label: Option<&'a str>,
+    /// Driver specific description of the message.
+    pub message: Option<&'a str>,
+}
+
+#[cfg(feature = "egl_debug")]
+type DebugCallback = Arc<dyn Fn(&DebugMessage) + Send + Sync>;
+
+#[cfg(feature = "egl_debug")]
+static DEBUG_CALLBACK: Mutex<Option<DebugCallback>> = Mutex::new(None);
+
+// Addresses of the label strings set with `label_object_str`. Labels set by other code
+// may be any pointer, so only these are read as strings.
+#[cfg(feature = "egl_debug")]
+static DEBUG_LABELS: Mutex<Option<HashSet<usize>>> = Mutex::new(None);
+
+#[cfg(feature = "egl_debug")]
+unsafe fn debug_str<'a>(c_str: *const c_char) -> Option<&'a str> {
+    if c_str.is_null() {
+        return None;
+    }
+    CStr::from_ptr(c_str).to_str().ok()
+}
+
+#[cfg(feature = "egl_debug")]
+unsafe extern "C" fn debug_callback_trampoline(error: EGLenum,
+                                               command: *const c_char,
+                                               message_type: EGLint,
+                                               _thread_label: EGLLabelKHR,
+                                               object_label: EGLLabelKHR,
+                                               message: *const c_char) {
+    // The lock is not held while calling, so the callback may call EGL again.
+    let callback = match DEBUG_CALLBACK.lock() {
+        Ok(callback) => callback.clone(),
+        Err(_) => return,
+    };
+    let callback = match callback {
+        Some(callback) => callback,
+        None => return,
+    };
+
+    let known_label = match DEBUG_LABELS.lock() {
+        Ok(labels) => labels.as_ref().is_some_and(|l| l.contains(&(object_label as usize))),
+        Err(_) => false,
+    };
+
+    let message = DebugMessage {
+        error,
+        command: debug_str(command),
+        message_type,
+        object_label: if known_label { debug_str(object_label as *const c_char) } else { None },
+        message: debug_str(message),
+    };
+
+    // Unwinding into EGL is undefined behavior, so a panicking callback is stopped here.
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| callback(&message)));
+}
+
+// -------------------------------------------------------------------------------------------------
+// ATTRIBUTE LISTS
+// -------------------------------------------------------------------------------------------------
+
+/// Returns the pointer passed to EGL for `attrib_list`, or null if it is empty.
+///
+/// EGL reads attribute lists until the `EGL_NONE` terminator, so an unterminated list
+/// makes it read past the end of the slice. Debug builds panic on such lists.
+fn attrib_list_ptr<T: Copy + PartialEq>(attrib_list: &[T], none: T) -> *const T {
+    if attrib_list.is_empty() {
+        return ptr::null();
+    }
+    debug_assert!(attrib_list.len() % 2 == 1 && attrib_list[attrib_list.len() - 1] == none,
+                  "EGL attribute list must be terminated with EGL_NONE");
+    attrib_list.as_ptr()
+}
 
 // -------------------------------------------------------------------------------------------------
 // FUNCTIONS
@@ -356,8 +1107,14 @@ pub const EGL_NO_IMAGE: EGLImage = 0 as EGLImage;
 /// ## api
 ///
 /// Specifies the client API to bind, one of EGL_OPENGL_API, EGL_OPENGL_ES_API, or EGL_OPENVG_API.
+///
+/// Returns `EglCallError::BindAPIUnsupported` if the implementation does not support
+/// the requested API.
 pub fn bind_api(api: EGLenum) -> EglCallResult<()> {
     if unsafe { ffi::eglBindAPI(api) } == EGL_FALSE {
+        if get_error() == error_code::EGL_BAD_PARAMETER {
+            return Err(EglCallError::BindAPIUnsupported);
+        }
         return Err(EglCallError::BindAPI);
     }
     Ok(())
@@ -378,19 +1135,19 @@ pub fn bind_tex_image(display: EGLDisplay,
 ///
 /// Calls `eglChooseConfig` internally.
 ///
-/// On failure returns `None`.
-pub fn num_filtered_configs(display: EGLDisplay, attrib_list: &[EGLint]) -> EglCallResult<i32> {
-    let mut count: i32 = 0;
+/// Fails if the call fails, or if EGL reports a negative count.
+pub fn num_filtered_configs(display: EGLDisplay, attrib_list: &[EGLint]) -> EglCallResult<usize> {
+    let mut count: EGLint = 0;
     if unsafe {
         ffi::eglChooseConfig(display,
-                             attrib_list.as_ptr(),
+                             attrib_list_ptr(attrib_list, config_attrib::EGL_NONE),
                              ptr::null_mut(),
                              0,
                              &mut count)
-    } != EGL_TRUE {
+    } != EGL_TRUE || count < 0 {
         return Err(EglCallError::ChooseConfig);
     }
-    Ok(count as i32)
+    Ok(count as usize)
 }
 
 /// `[EGL 1.0]` Return a list of EGL frame buffer configurations that match specified attributes.
@@ -405,7 +1162,7 @@ pub fn get_filtered_configs(display: EGLDisplay,
     let mut count: i32 = 0;
     if unsafe {
         ffi::eglChooseConfig(display,
-                             attrib_list.as_ptr(),
+                             attrib_list_ptr(attrib_list, config_attrib::EGL_NONE),
                              mem::transmute(configs.as_mut_ptr()),
                              configs.len() as i32,
                              &mut count)
@@ -415,6 +1172,22 @@ pub fn get_filtered_configs(display: EGLDisplay,
     Ok(count as i32)
 }
 
+/// `[EGL 1.5]` Wait in the client for a sync object to be signalled.
+///
+/// Returns `EGL_CONDITION_SATISFIED` or `EGL_TIMEOUT_EXPIRED`.
+#[cfg(egl_has_1_5)]
+pub fn client_wait_sync(display: EGLDisplay,
+                        sync: EGLSync,
+                        flags: EGLint,
+                        timeout: EGLTime)
+                        -> EglCallResult<EGLint> {
+    let result = unsafe { ffi::eglClientWaitSync(display, sync, flags, timeout) };
+    if result == EGL_FALSE as EGLint {
+        return Err(EglCallError::ClientWaitSync);
+    }
+    Ok(result)
+}
+
 /// `[EGL 1.0]` Copy EGL surface color buffer to a native pixmap.
 pub fn copy_buffers(display: EGLDisplay,
                     surface: EGLSurface,
@@ -440,13 +1213,16 @@ pub fn create_context(display: EGLDisplay, config: EGLConfig) -> EglCallResult<E
 }
 
 /// `[EGL 1.0]` Create a new EGL rendering context.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`; debug builds panic otherwise.
 pub fn create_context_with_attribs(display: EGLDisplay,
                                    config: EGLConfig,
                                    share_context: EGLContext,
                                    attrib_list: &[EGLint])
                                    -> EglCallResult<EGLContext> {
     unsafe {
-        let context = ffi::eglCreateContext(display, config, share_context, attrib_list.as_ptr());
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE);
+        let context = ffi::eglCreateContext(display, config, share_context, attribs);
 
         if !context.is_null() {
             Ok(context)
@@ -464,11 +1240,7 @@ pub fn create_pbuffer_from_client_buffer(display: EGLDisplay,
                                          attrib_list: &[EGLint])
                                          -> EglCallResult<EGLSurface> {
     unsafe {
-        let attribs = if attrib_list.len() > 0 {
-            attrib_list.as_ptr()
-        } else {
-            ptr::null()
-        };
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE);
 
         let surface = ffi::eglCreatePbufferFromClientBuffer(display,
                                                             buffer_type,
@@ -484,17 +1256,38 @@ pub fn create_pbuffer_from_client_buffer(display: EGLDisplay,
     }
 }
 
+/// `[EGL 1.5]` Create a new EGL image from a client API or native buffer.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`.
+#[cfg(egl_has_1_5)]
+pub fn create_image(display: EGLDisplay,
+                    context: EGLContext,
+                    target: EGLenum,
+                    buffer: EGLClientBuffer,
+                    attrib_list: &[EGLAttrib])
+                    -> EglCallResult<EGLImage> {
+    unsafe {
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE as EGLAttrib);
+
+        let image = ffi::eglCreateImage(display, context, target, buffer, attribs);
+
+        if !image.is_null() {
+            Ok(image)
+        } else {
+            Err(EglCallError::CreateImage)
+        }
+    }
+}
+
 /// `[EGL 1.0]` Create a new EGL pixel buffer surface.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`; debug builds panic otherwise.
 pub fn create_pbuffer_surface(display: EGLDisplay,
                               config: EGLConfig,
                               attrib_list: &[EGLint])
                               -> EglCallResult<EGLSurface> {
     unsafe {
-        let attribs = if attrib_list.len() > 0 {
-            attrib_list.as_ptr()
-        } else {
-            ptr::null()
-        };
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE);
 
         let surface = ffi::eglCreatePbufferSurface(display, config, attribs);
 
@@ -507,17 +1300,15 @@ pub fn create_pbuffer_surface(display: EGLDisplay,
 }
 
 /// `[EGL 1.0]` Create a new EGL pixmap surface.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`; debug builds panic otherwise.
 pub fn create_pixmap_surface(display: EGLDisplay,
                              config: EGLConfig,
                              pixmap: EGLNativePixmapType,
                              attrib_list: &[EGLint])
                              -> EglCallResult<EGLSurface> {
     unsafe {
-        let attribs = if attrib_list.len() > 0 {
-            attrib_list.as_ptr()
-        } else {
-            ptr::null()
-        };
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE);
 
         let surface = ffi::eglCreatePixmapSurface(display, config, pixmap, attribs);
 
@@ -552,7 +1343,8 @@ pub fn create_window_surface_with_attribs(display: EGLDisplay,
                                           attrib_list: &[EGLint])
                                           -> EglCallResult<EGLSurface> {
     unsafe {
-        let surface = ffi::eglCreateWindowSurface(display, config, window, attrib_list.as_ptr());
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE);
+        let surface = ffi::eglCreateWindowSurface(display, config, window, attribs);
 
         if !surface.is_null() {
             Ok(surface)
@@ -563,18 +1355,14 @@ pub fn create_window_surface_with_attribs(display: EGLDisplay,
 }
 
 /// `[EGL 1.5]` Create a new EGL window surface.
-#[cfg(feature = "egl_1_5")]
+#[cfg(egl_has_1_5)]
 pub fn create_platform_window_surface(display: EGLDisplay,
                                       config: EGLConfig,
                                       native_window: *mut c_void,
                                       attrib_list: &[EGLAttrib])
                                       -> EglCallResult<EGLSurface> {
     unsafe {
-        let attribs = if attrib_list.len() > 0 {
-            attrib_list.as_ptr()
-        } else {
-            ptr::null()
-        };
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE as EGLAttrib);
 
         let surface = ffi::eglCreatePlatformWindowSurface(display, config, native_window, attribs);
 
@@ -586,6 +1374,25 @@ pub fn create_platform_window_surface(display: EGLDisplay,
     }
 }
 
+/// `[EGL 1.5]` Create a new EGL sync object.
+#[cfg(egl_has_1_5)]
+pub fn create_sync(display: EGLDisplay,
+                   sync_type: EGLenum,
+                   attrib_list: &[EGLAttrib])
+                   -> EglCallResult<EGLSync> {
+    unsafe {
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE as EGLAttrib);
+
+        let sync = ffi::eglCreateSync(display, sync_type, attribs);
+
+        if !sync.is_null() {
+            Ok(sync)
+        } else {
+            Err(EglCallError::CreateSync)
+        }
+    }
+}
+
 /// `[EGL 1.0]` Destroy an EGL rendering context.
 pub fn destroy_context(display: EGLDisplay, ctx: EGLContext) -> EglCallResult<()> {
     if unsafe { ffi::eglDestroyContext(display, ctx) } != EGL_TRUE {
@@ -594,6 +1401,15 @@ pub fn destroy_context(display: EGLDisplay, ctx: EGLContext) -> EglCallResult<()
     Ok(())
 }
 
+/// `[EGL 1.5]` Destroy an EGL image.
+#[cfg(egl_has_1_5)]
+pub fn destroy_image(display: EGLDisplay, image: EGLImage) -> EglCallResult<()> {
+    if unsafe { ffi::eglDestroyImage(display, image) } != EGL_TRUE {
+        return Err(EglCallError::DestroyImage);
+    }
+    Ok(())
+}
+
 /// `[EGL 1.0]` Destroy an EGL surface.
 pub fn destroy_surface(display: EGLDisplay, surface: EGLSurface) -> EglCallResult<()> {
     if unsafe { ffi::eglDestroySurface(display, surface) } != EGL_TRUE {
@@ -602,6 +1418,15 @@ pub fn destroy_surface(display: EGLDisplay, surface: EGLSurface) -> EglCallResul
     Ok(())
 }
 
+/// `[EGL 1.5]` Destroy a sync object.
+#[cfg(egl_has_1_5)]
+pub fn destroy_sync(display: EGLDisplay, sync: EGLSync) -> EglCallResult<()> {
+    if unsafe { ffi::eglDestroySync(display, sync) } != EGL_TRUE {
+        return Err(EglCallError::DestroySync);
+    }
+    Ok(())
+}
+
 /// `[EGL 1.0]` Return information about an EGL frame buffer configuration.
 pub fn get_config_attrib(display: EGLDisplay,
                          config: EGLConfig,
@@ -614,15 +1439,34 @@ pub fn get_config_attrib(display: EGLDisplay,
     Ok(())
 }
 
+/// `[EGL 1.0]` Return several values of an EGL frame buffer configuration.
+///
+/// Calls `eglGetConfigAttrib` for each of `attributes`, and writes the value into the
+/// same position of `out`, which must have the same length. Stops at the first
+/// failing call.
+pub fn get_config_attribs(display: EGLDisplay,
+                          config: EGLConfig,
+                          attributes: &[EGLint],
+                          out: &mut [EGLint])
+                          -> EglCallResult<()> {
+    assert_eq!(attributes.len(), out.len());
+
+    for (&attribute, value) in attributes.iter().zip(out.iter_mut()) {
+        get_config_attrib(display, config, attribute, value)?;
+    }
+    Ok(())
+}
+
 /// `[EGL 1.0]` Return the total number of all available display configs.
 ///
-/// On failure returns `None`.
-pub fn num_configs(display: EGLDisplay) -> EglCallResult<i32> {
-    let mut count: i32 = 0;
-    if unsafe { ffi::eglGetConfigs(display, ptr::null_mut(), 0, &mut count) } != EGL_TRUE {
+/// Fails if the call fails, or if EGL reports a negative count.
+pub fn num_configs(display: EGLDisplay) -> EglCallResult<usize> {
+    let mut count: EGLint = 0;
+    if unsafe { ffi::eglGetConfigs(display, ptr::null_mut(), 0, &mut count) } != EGL_TRUE ||
+       count < 0 {
         return Err(EglCallError::GetConfigs);
     }
-    Ok(count as i32)
+    Ok(count as usize)
 }
 
 /// `[EGL 1.0]` Return a list of all EGL frame buffer configurations for a display.
@@ -641,6 +1485,28 @@ pub fn get_configs(display: EGLDisplay, configs: &mut [EGLConfig]) -> EglCallRes
     Ok(count as i32)
 }
 
+/// `[EGL 1.0]` Return all EGL frame buffer configurations for a display.
+///
+/// Gets the config count first, then fills a buffer of that size. Returns an empty
+/// list without the second call if the display has no configs.
+///
+/// A negative count returned by EGL is treated as zero. Fails with
+/// `EglCallError::GetConfigs` if EGL reports more configs than the buffer holds.
+pub fn get_configs_vec(display: EGLDisplay) -> EglCallResult<Vec<EGLConfig>> {
+    let count = num_configs(display)?;
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut configs: Vec<EGLConfig> = vec![ptr::null_mut(); count];
+    let returned_count = get_configs(display, &mut configs)?.max(0) as usize;
+    if returned_count > count {
+        return Err(EglCallError::GetConfigs);
+    }
+    configs.truncate(returned_count);
+    Ok(configs)
+}
+
 /// `[EGL 1.4]` Return the current EGL rendering context.
 pub fn get_current_context() -> EglCallResult<EGLContext> {
     unsafe {
@@ -693,11 +1559,40 @@ pub fn get_display(display_id: EGLNativeDisplayType) -> EglCallResult<EGLDisplay
     }
 }
 
+/// `[EGL 1.0]` Return an EGL display connection for a typed native display.
+///
+/// Same as `get_display`, but `NativeDisplay::Default` makes the default display case
+/// explicit.
+pub fn get_display_typed(native_display: NativeDisplay) -> EglCallResult<EGLDisplay> {
+    get_display(native_display.as_raw())
+}
+
 /// `[EGL 1.0]` Return error information.
 pub fn get_error() -> EGLint {
     unsafe { ffi::eglGetError() }
 }
 
+/// `[EGL 1.5]` Return an EGL display connection for a native display of the given platform.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`.
+#[cfg(egl_has_1_5)]
+pub fn get_platform_display(platform: EGLenum,
+                            native_display: *mut c_void,
+                            attrib_list: &[EGLAttrib])
+                            -> EglCallResult<EGLDisplay> {
+    unsafe {
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE as EGLAttrib);
+
+        let display = ffi::eglGetPlatformDisplay(platform, native_display, attribs);
+
+        if !display.is_null() {
+            Ok(display)
+        } else {
+            Err(EglCallError::GetPlatformDisplay)
+        }
+    }
+}
+
 /// `[EGL 1.0]` Return a GL or an EGL extension function.
 pub fn get_proc_address(procname: &str) -> extern "C" fn() {
     unsafe {
@@ -726,6 +1621,13 @@ pub fn initialize_and_get_version(display: EGLDisplay,
     Ok(())
 }
 
+/// `[EGL 1.0]` Initialize an EGL display connection and return EGL version.
+pub fn initialize_get_version(display: EGLDisplay) -> EglCallResult<Version> {
+    let (mut major, mut minor) = (0, 0);
+    initialize_and_get_version(display, &mut major, &mut minor)?;
+    Ok(Version { major, minor })
+}
+
 /// `[EGL 1.0]` Attach an EGL rendering context to EGL surfaces.
 pub fn make_current(display: EGLDisplay,
                     draw: EGLSurface,
@@ -738,6 +1640,28 @@ pub fn make_current(display: EGLDisplay,
     Ok(())
 }
 
+/// `[EGL 1.0]` Attach an EGL rendering context to EGL surfaces, returning the
+/// previous binding of the calling thread.
+///
+/// Lets library code temporarily make its own context current and put back the
+/// caller's binding with `PreviousCurrent::restore` afterwards. Only the binding of
+/// the current rendering API is saved.
+pub fn make_current_saving(display: EGLDisplay,
+                           draw: EGLSurface,
+                           read: EGLSurface,
+                           ctx: EGLContext)
+                           -> EglCallResult<PreviousCurrent> {
+    let previous = PreviousCurrent {
+        display,
+        previous_display: get_current_display().unwrap_or(EGL_NO_DISPLAY),
+        draw: get_current_surface(EGL_DRAW).unwrap_or(EGL_NO_SURFACE),
+        read: get_current_surface(EGL_READ).unwrap_or(EGL_NO_SURFACE),
+        context: get_current_context().unwrap_or(EGL_NO_CONTEXT),
+    };
+    make_current(display, draw, read, ctx)?;
+    Ok(previous)
+}
+
 /// `[EGL 1.2]` Query the current rendering API.
 pub fn query_api() -> EGLenum {
     unsafe { ffi::eglQueryAPI() }
@@ -755,6 +1679,19 @@ pub fn query_context(display: EGLDisplay,
     Ok(())
 }
 
+/// `[EGL 1.0]` Return a value of EGL rendering context information.
+///
+/// Same as `query_context`, but returns the value instead of writing it to an
+/// out-parameter.
+pub fn query_context_value(display: EGLDisplay,
+                           ctx: EGLContext,
+                           attribute: EGLint)
+                           -> EglCallResult<EGLint> {
+    let mut value: EGLint = 0;
+    query_context(display, ctx, attribute, &mut value)?;
+    Ok(value)
+}
+
 /// `[EGL 1.0]` Return a string describing an EGL display connection.
 pub fn query_string(display: EGLDisplay, name: EGLint) -> EglCallResult<&'static CStr> {
     unsafe {
@@ -780,6 +1717,19 @@ pub fn query_surface(display: EGLDisplay,
     Ok(())
 }
 
+/// `[EGL 1.0]` Return a value of EGL surface information.
+///
+/// Same as `query_surface`, but returns the value instead of writing it to an
+/// out-parameter.
+pub fn query_surface_value(display: EGLDisplay,
+                           surface: EGLSurface,
+                           attribute: EGLint)
+                           -> EglCallResult<EGLint> {
+    let mut value: EGLint = 0;
+    query_surface(display, surface, attribute, &mut value)?;
+    Ok(value)
+}
+
 /// `[EGL 1.1]` Releases a color buffer that is being used as a texture.
 pub fn release_tex_image(display: EGLDisplay,
                          surface: EGLSurface,
@@ -859,3 +1809,539 @@ pub fn wait_native(engine: EGLint) -> EglCallResult<()> {
     }
     Ok(())
 }
+
+/// `[EGL 1.0]` Complete native execution of a typed engine prior to subsequent GL
+/// rendering calls.
+///
+/// Same as `wait_native`, but `NativeEngine::Core` makes the standard engine explicit.
+pub fn wait_native_typed(engine: NativeEngine) -> EglCallResult<()> {
+    wait_native(engine.as_raw())
+}
+
+/// `[EGL 1.5]` Wait in the server for a sync object to be signalled.
+#[cfg(egl_has_1_5)]
+pub fn wait_sync(display: EGLDisplay, sync: EGLSync, flags: EGLint) -> EglCallResult<()> {
+    if unsafe { ffi::eglWaitSync(display, sync, flags) } != EGL_TRUE {
+        return Err(EglCallError::WaitSync);
+    }
+    Ok(())
+}
+
+// -------------------------------------------------------------------------------------------------
+// EXTENSION FUNCTIONS
+// -------------------------------------------------------------------------------------------------
+
+/// `[EGL_KHR_fence_sync]` Create a new EGL sync object.
+pub fn create_sync_khr(display: EGLDisplay,
+                       sync_type: EGLenum,
+                       attrib_list: &[EGLint])
+                       -> error::Result<EGLSyncKHR> {
+    unsafe {
+        let create: ffi::PFNEGLCREATESYNCKHRPROC = load_extension_function!("eglCreateSyncKHR")?;
+
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE);
+
+        let sync = create(display, sync_type, attribs);
+
+        if !sync.is_null() {
+            Ok(sync)
+        } else {
+            Err(EglCallError::CreateSyncKHR.into())
+        }
+    }
+}
+
+/// `[EGL_KHR_fence_sync]` Destroy a sync object.
+pub fn destroy_sync_khr(display: EGLDisplay, sync: EGLSyncKHR) -> error::Result<()> {
+    unsafe {
+        let destroy: ffi::PFNEGLDESTROYSYNCKHRPROC = load_extension_function!("eglDestroySyncKHR")?;
+
+        if destroy(display, sync) != EGL_TRUE {
+            return Err(EglCallError::DestroySyncKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_KHR_fence_sync]` Wait in the client for a sync object to be signalled.
+///
+/// Returns `EGL_CONDITION_SATISFIED_KHR` or `EGL_TIMEOUT_EXPIRED_KHR`.
+pub fn client_wait_sync_khr(display: EGLDisplay,
+                            sync: EGLSyncKHR,
+                            flags: EGLint,
+                            timeout: EGLTimeKHR)
+                            -> error::Result<EGLint> {
+    unsafe {
+        let client_wait: ffi::PFNEGLCLIENTWAITSYNCKHRPROC =
+            load_extension_function!("eglClientWaitSyncKHR")?;
+
+        let result = client_wait(display, sync, flags, timeout);
+        if result == EGL_FALSE as EGLint {
+            return Err(EglCallError::ClientWaitSyncKHR.into());
+        }
+        Ok(result)
+    }
+}
+
+/// `[EGL_KHR_wait_sync]` Wait in the server for a sync object to be signalled.
+pub fn wait_sync_khr(display: EGLDisplay, sync: EGLSyncKHR, flags: EGLint) -> error::Result<()> {
+    unsafe {
+        let wait: ffi::PFNEGLWAITSYNCKHRPROC = load_extension_function!("eglWaitSyncKHR")?;
+
+        if wait(display, sync, flags) != EGL_TRUE as EGLint {
+            return Err(EglCallError::WaitSyncKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_ANDROID_native_fence_sync]` Create a native fence file descriptor from a
+/// native fence sync object.
+///
+/// The returned file descriptor is owned by the caller.
+pub fn dup_native_fence_fd_android(display: EGLDisplay,
+                                   sync: EGLSyncKHR)
+                                   -> error::Result<EGLint> {
+    unsafe {
+        let dup: ffi::PFNEGLDUPNATIVEFENCEFDANDROIDPROC =
+            load_extension_function!("eglDupNativeFenceFDANDROID")?;
+
+        let fd = dup(display, sync);
+        if fd == sync::EGL_NO_NATIVE_FENCE_FD_ANDROID {
+            return Err(EglCallError::DupNativeFenceFDANDROID.into());
+        }
+        Ok(fd)
+    }
+}
+
+/// `[EGL_EXT_image_dma_buf_import_modifiers]` Query the dmabuf formats supported by the
+/// display, as DRM fourcc codes.
+///
+/// Writes at most `formats.len()` formats. If `formats` is empty, only the total
+/// number of formats is queried. Returns the number of formats written or available.
+pub fn query_dmabuf_formats_ext(display: EGLDisplay,
+                                formats: &mut [EGLint])
+                                -> error::Result<EGLint> {
+    unsafe {
+        let query: ffi::PFNEGLQUERYDMABUFFORMATSEXTPROC =
+            load_extension_function!("eglQueryDmaBufFormatsEXT")?;
+
+        let mut count: EGLint = 0;
+        let ptr = if formats.is_empty() {
+            ptr::null_mut()
+        } else {
+            formats.as_mut_ptr()
+        };
+
+        if query(display, formats.len() as EGLint, ptr, &mut count) != EGL_TRUE {
+            return Err(EglCallError::QueryDmaBufFormatsEXT.into());
+        }
+        Ok(count)
+    }
+}
+
+/// `[EGL_EXT_image_dma_buf_import_modifiers]` Query the format modifiers supported by
+/// the display for a dmabuf format.
+///
+/// Writes at most `modifiers.len()` modifiers, and for each one whether it is only
+/// supported with the `GL_TEXTURE_EXTERNAL_OES` target into `external_only`, which
+/// must be empty or as long as `modifiers`. If `modifiers` is empty, only the total
+/// number of modifiers is queried. Returns the number of modifiers written or available.
+pub fn query_dmabuf_modifiers_ext(display: EGLDisplay,
+                                  format: EGLint,
+                                  modifiers: &mut [EGLuint64KHR],
+                                  external_only: &mut [EGLBoolean])
+                                  -> error::Result<EGLint> {
+    assert!(external_only.is_empty() || external_only.len() == modifiers.len());
+
+    unsafe {
+        let query: ffi::PFNEGLQUERYDMABUFMODIFIERSEXTPROC =
+            load_extension_function!("eglQueryDmaBufModifiersEXT")?;
+
+        let mut count: EGLint = 0;
+        let modifiers_ptr = if modifiers.is_empty() {
+            ptr::null_mut()
+        } else {
+            modifiers.as_mut_ptr()
+        };
+        let external_only_ptr = if external_only.is_empty() {
+            ptr::null_mut()
+        } else {
+            external_only.as_mut_ptr()
+        };
+
+        if query(display,
+                 format,
+                 modifiers.len() as EGLint,
+                 modifiers_ptr,
+                 external_only_ptr,
+                 &mut count) != EGL_TRUE {
+            return Err(EglCallError::QueryDmaBufModifiersEXT.into());
+        }
+        Ok(count)
+    }
+}
+
+/// Query the DRM fourcc format and the plane count of an image. Fails if EGL reports
+/// no planes.
+#[cfg(all(egl_has_1_5, feature = "egl_dmabuf_export"))]
+fn export_dmabuf_image_planes(display: EGLDisplay,
+                              image: EGLImage)
+                              -> error::Result<(EGLint, usize)> {
+    unsafe {
+        let query: ffi::PFNEGLEXPORTDMABUFIMAGEQUERYMESAPROC =
+            load_extension_function!("eglExportDMABUFImageQueryMESA")?;
+
+        let mut fourcc: EGLint = 0;
+        let mut num_planes: EGLint = 0;
+        if query(display, image, &mut fourcc, &mut num_planes, ptr::null_mut()) != EGL_TRUE ||
+           num_planes < 1 {
+            return Err(EglCallError::ExportDMABUFImageQueryMESA.into());
+        }
+        Ok((fourcc, num_planes as usize))
+    }
+}
+
+/// `[EGL_MESA_image_dma_buf_export]` Query the DRM fourcc format and per-plane format
+/// modifiers of an image, before exporting it as dmabuf.
+///
+/// Returns `(fourcc, modifiers)`, with one modifier for each plane. Fails with
+/// `EglCallError::ExportDMABUFImageQueryMESA` if EGL reports no planes.
+#[cfg(all(egl_has_1_5, feature = "egl_dmabuf_export"))]
+pub fn export_dmabuf_image_query_mesa(display: EGLDisplay,
+                                      image: EGLImage)
+                                      -> error::Result<(EGLint, Vec<EGLuint64KHR>)> {
+    let (_, num_planes) = export_dmabuf_image_planes(display, image)?;
+    let mut modifiers: Vec<EGLuint64KHR> = vec![0; num_planes];
+
+    unsafe {
+        let query: ffi::PFNEGLEXPORTDMABUFIMAGEQUERYMESAPROC =
+            load_extension_function!("eglExportDMABUFImageQueryMESA")?;
+
+        let mut fourcc: EGLint = 0;
+        let mut returned_planes: EGLint = 0;
+        if query(display,
+                 image,
+                 &mut fourcc,
+                 &mut returned_planes,
+                 modifiers.as_mut_ptr()) != EGL_TRUE {
+            return Err(EglCallError::ExportDMABUFImageQueryMESA.into());
+        }
+        Ok((fourcc, modifiers))
+    }
+}
+
+/// `[EGL_MESA_image_dma_buf_export]` Export an image as dmabuf file descriptors.
+///
+/// Returns `(fds, strides, offsets)`, with one file descriptor, stride and offset for
+/// each plane. The returned file descriptors are owned by the caller. Fails with
+/// `EglCallError::ExportDMABUFImageQueryMESA` if EGL reports no planes.
+#[cfg(all(egl_has_1_5, feature = "egl_dmabuf_export"))]
+pub fn export_dmabuf_image_mesa(display: EGLDisplay,
+                                image: EGLImage)
+                                -> error::Result<(Vec<EGLint>, Vec<EGLint>, Vec<EGLint>)> {
+    let (_, num_planes) = export_dmabuf_image_planes(display, image)?;
+    let mut fds: Vec<EGLint> = vec![-1; num_planes];
+    let mut strides: Vec<EGLint> = vec![0; num_planes];
+    let mut offsets: Vec<EGLint> = vec![0; num_planes];
+
+    unsafe {
+        let export: ffi::PFNEGLEXPORTDMABUFIMAGEMESAPROC =
+            load_extension_function!("eglExportDMABUFImageMESA")?;
+
+        if export(display,
+                  image,
+                  fds.as_mut_ptr(),
+                  strides.as_mut_ptr(),
+                  offsets.as_mut_ptr()) != EGL_TRUE {
+            return Err(EglCallError::ExportDMABUFImageMESA.into());
+        }
+    }
+    Ok((fds, strides, offsets))
+}
+
+/// `[EGL_EXT_device_query]` Return an attribute of an EGL display connection, such as
+/// `EGL_DEVICE_EXT`.
+pub fn query_display_attrib_ext(display: EGLDisplay,
+                                attribute: EGLint)
+                                -> error::Result<EGLAttrib> {
+    unsafe {
+        let query: ffi::PFNEGLQUERYDISPLAYATTRIBEXTPROC =
+            load_extension_function!("eglQueryDisplayAttribEXT")?;
+
+        let mut value: EGLAttrib = 0;
+        if query(display, attribute, &mut value) != EGL_TRUE {
+            return Err(EglCallError::QueryDisplayAttribEXT.into());
+        }
+        Ok(value)
+    }
+}
+
+/// `[EGL_EXT_device_query]` Return a string describing an EGL device, such as
+/// `EGL_EXTENSIONS`.
+pub fn query_device_string_ext(device: EGLDeviceEXT,
+                               name: EGLint)
+                               -> error::Result<&'static CStr> {
+    unsafe {
+        let query: ffi::PFNEGLQUERYDEVICESTRINGEXTPROC =
+            load_extension_function!("eglQueryDeviceStringEXT")?;
+
+        let c_str = query(device, name);
+
+        if !c_str.is_null() {
+            Ok(CStr::from_ptr(c_str))
+        } else {
+            Err(EglCallError::QueryDeviceStringEXT.into())
+        }
+    }
+}
+
+/// `[EGL_KHR_debug]` Set a callback invoked with EGL errors and other debug messages,
+/// instead of polling `get_error`.
+///
+/// The callback is process wide and may be invoked from any thread that calls EGL,
+/// so it must be `Send` and `Sync`. Only critical and error messages are reported,
+/// which is the EGL default. Replaces the previously set callback. Panics in the
+/// callback are caught, since they can not unwind through EGL.
+#[cfg(feature = "egl_debug")]
+pub fn set_debug_callback(callback: Box<dyn Fn(&DebugMessage) + Send + Sync>)
+                          -> error::Result<()> {
+    *DEBUG_CALLBACK.lock().map_err(|_| error::Error::DebugStatePoisoned)? =
+        Some(Arc::from(callback));
+    debug_message_control_khr(Some(debug_callback_trampoline), &[])
+}
+
+/// `[EGL_KHR_debug]` Remove the callback set with `set_debug_callback`.
+#[cfg(feature = "egl_debug")]
+pub fn clear_debug_callback() -> error::Result<()> {
+    debug_message_control_khr(None, &[])?;
+    *DEBUG_CALLBACK.lock().map_err(|_| error::Error::DebugStatePoisoned)? = None;
+    Ok(())
+}
+
+/// `[EGL_KHR_debug]` Set the debug callback and enable or disable message types.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`; debug builds panic otherwise.
+#[cfg(feature = "egl_debug")]
+pub fn debug_message_control_khr(callback: ffi::EGLDEBUGPROCKHR,
+                                 attrib_list: &[EGLAttrib])
+                                 -> error::Result<()> {
+    unsafe {
+        let control: ffi::PFNEGLDEBUGMESSAGECONTROLKHRPROC =
+            load_extension_function!("eglDebugMessageControlKHR")?;
+
+        let attribs = attrib_list_ptr(attrib_list, config_attrib::EGL_NONE as EGLAttrib);
+
+        if control(callback, attribs) != error_code::EGL_SUCCESS {
+            return Err(EglCallError::DebugMessageControlKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_KHR_debug]` Attach a label to an EGL object, which is passed back to the
+/// debug callback with messages about the object.
+#[cfg(feature = "egl_debug")]
+pub fn label_object_khr(display: EGLDisplay,
+                        object_type: EGLenum,
+                        object: EGLObjectKHR,
+                        label: EGLLabelKHR)
+                        -> error::Result<()> {
+    unsafe {
+        let label_object: ffi::PFNEGLLABELOBJECTKHRPROC =
+            load_extension_function!("eglLabelObjectKHR")?;
+
+        if label_object(display, object_type, object, label) != error_code::EGL_SUCCESS {
+            return Err(EglCallError::LabelObjectKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_KHR_debug]` Attach a string label to an EGL object, which is passed back to
+/// the debug callback as `DebugMessage::object_label`.
+///
+/// EGL keeps referring to the label, so the string is copied and never freed; set
+/// labels once per object.
+#[cfg(feature = "egl_debug")]
+pub fn label_object_str(display: EGLDisplay,
+                        object_type: EGLenum,
+                        object: EGLObjectKHR,
+                        name: &str)
+                        -> error::Result<()> {
+    let label = CString::new(name.replace('\0', "")).unwrap().into_raw();
+
+    // The lock is not held while labeling, since EGL may report errors to the debug
+    // callback right away.
+    if let Err(e) = label_object_khr(display, object_type, object, label as EGLLabelKHR) {
+        drop(unsafe { CString::from_raw(label) });
+        return Err(e);
+    }
+    DEBUG_LABELS.lock()
+                .map_err(|_| error::Error::DebugStatePoisoned)?
+                .get_or_insert_with(HashSet::new)
+                .insert(label as usize);
+    Ok(())
+}
+
+/// `[EGL_KHR_partial_update]` Set the region of the surface that will be rendered in
+/// the current frame.
+///
+/// `rects` contains rectangles as `[x, y, width, height]`, with the origin at the lower
+/// left corner of the surface.
+pub fn set_damage_region_khr(display: EGLDisplay,
+                             surface: EGLSurface,
+                             rects: &[[EGLint; 4]])
+                             -> error::Result<()> {
+    unsafe {
+        let set: ffi::PFNEGLSETDAMAGEREGIONKHRPROC =
+            load_extension_function!("eglSetDamageRegionKHR")?;
+
+        // EGL reads `4 * n_rects` values, and does not write to them.
+        let rects_ptr = if rects.is_empty() {
+            ptr::null_mut()
+        } else {
+            rects.as_ptr() as *const EGLint as *mut EGLint
+        };
+
+        if set(display, surface, rects_ptr, rects.len() as EGLint) != EGL_TRUE {
+            return Err(EglCallError::SetDamageRegionKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_ANDROID_get_frame_timestamps]` Get the identifier of the next frame that
+/// will be swapped on the surface.
+pub fn get_next_frame_id_android(display: EGLDisplay,
+                                 surface: EGLSurface)
+                                 -> error::Result<EGLuint64KHR> {
+    unsafe {
+        let get: ffi::PFNEGLGETNEXTFRAMEIDANDROIDPROC =
+            load_extension_function!("eglGetNextFrameIdANDROID")?;
+
+        let mut frame_id: EGLuint64KHR = 0;
+        if get(display, surface, &mut frame_id) != EGL_TRUE {
+            return Err(EglCallError::GetNextFrameIdANDROID.into());
+        }
+        Ok(frame_id)
+    }
+}
+
+/// `[EGL_ANDROID_get_frame_timestamps]` Get timestamps of a frame swapped on the
+/// surface.
+///
+/// Writes the value of each of `timestamps` into the same position of `values`, which
+/// must have the same length. Values are `EGL_TIMESTAMP_PENDING_ANDROID` if not yet
+/// known, and `EGL_TIMESTAMP_INVALID_ANDROID` if they never will be.
+pub fn get_frame_timestamps_android(display: EGLDisplay,
+                                    surface: EGLSurface,
+                                    frame_id: EGLuint64KHR,
+                                    timestamps: &[EGLint],
+                                    values: &mut [EGLnsecsANDROID])
+                                    -> error::Result<()> {
+    assert_eq!(timestamps.len(), values.len());
+
+    unsafe {
+        let get: ffi::PFNEGLGETFRAMETIMESTAMPSANDROIDPROC =
+            load_extension_function!("eglGetFrameTimestampsANDROID")?;
+
+        if get(display,
+               surface,
+               frame_id,
+               timestamps.len() as EGLint,
+               timestamps.as_ptr(),
+               values.as_mut_ptr()) != EGL_TRUE {
+            return Err(EglCallError::GetFrameTimestampsANDROID.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_KHR_stream]` Create a new stream.
+///
+/// `attrib_list` must be empty or terminated with `EGL_NONE`; debug builds panic otherwise.
+#[cfg(feature = "egl_stream")]
+pub fn create_stream_khr(display: EGLDisplay,
+                         attrib_list: &[EGLint])
+                         -> error::Result<EGLStreamKHR> {
+    unsafe {
+        let create: ffi::PFNEGLCREATESTREAMKHRPROC =
+            load_extension_function!("eglCreateStreamKHR")?;
+
+        let stream = create(display, attrib_list_ptr(attrib_list, config_attrib::EGL_NONE));
+
+        if !stream.is_null() {
+            Ok(stream)
+        } else {
+            Err(EglCallError::CreateStreamKHR.into())
+        }
+    }
+}
+
+/// `[EGL_KHR_stream]` Destroy a stream.
+#[cfg(feature = "egl_stream")]
+pub fn destroy_stream_khr(display: EGLDisplay, stream: EGLStreamKHR) -> error::Result<()> {
+    unsafe {
+        let destroy: ffi::PFNEGLDESTROYSTREAMKHRPROC =
+            load_extension_function!("eglDestroyStreamKHR")?;
+
+        if destroy(display, stream) != EGL_TRUE {
+            return Err(EglCallError::DestroyStreamKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_KHR_stream]` Set a stream attribute, such as `EGL_CONSUMER_LATENCY_USEC_KHR`.
+#[cfg(feature = "egl_stream")]
+pub fn stream_attrib_khr(display: EGLDisplay,
+                         stream: EGLStreamKHR,
+                         attribute: EGLenum,
+                         value: EGLint)
+                         -> error::Result<()> {
+    unsafe {
+        let set: ffi::PFNEGLSTREAMATTRIBKHRPROC = load_extension_function!("eglStreamAttribKHR")?;
+
+        if set(display, stream, attribute, value) != EGL_TRUE {
+            return Err(EglCallError::StreamAttribKHR.into());
+        }
+    }
+    Ok(())
+}
+
+/// `[EGL_KHR_stream]` Return a stream attribute, such as `EGL_STREAM_STATE_KHR`.
+#[cfg(feature = "egl_stream")]
+pub fn query_stream_khr(display: EGLDisplay,
+                        stream: EGLStreamKHR,
+                        attribute: EGLenum)
+                        -> error::Result<EGLint> {
+    unsafe {
+        let query: ffi::PFNEGLQUERYSTREAMKHRPROC = load_extension_function!("eglQueryStreamKHR")?;
+
+        let mut value: EGLint = 0;
+        if query(display, stream, attribute, &mut value) != EGL_TRUE {
+            return Err(EglCallError::QueryStreamKHR.into());
+        }
+        Ok(value)
+    }
+}
+
+/// `[EGL_KHR_stream]` Return a 64 bit stream attribute, such as
+/// `EGL_PRODUCER_FRAME_KHR`.
+#[cfg(feature = "egl_stream")]
+pub fn query_stream_u64_khr(display: EGLDisplay,
+                            stream: EGLStreamKHR,
+                            attribute: EGLenum)
+                            -> error::Result<EGLuint64KHR> {
+    unsafe {
+        let query: ffi::PFNEGLQUERYSTREAMU64KHRPROC =
+            load_extension_function!("eglQueryStreamu64KHR")?;
+
+        let mut value: EGLuint64KHR = 0;
+        if query(display, stream, attribute, &mut value) != EGL_TRUE {
+            return Err(EglCallError::QueryStreamu64KHR.into());
+        }
+        Ok(value)
+    }
+}
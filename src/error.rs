@@ -7,15 +7,20 @@
 
 //! Error and Result types.
 
+use std::error;
+use std::fmt;
 use std::result;
 use std::str;
+use egl;
+use {RenderableType, VersionParseError};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum EglCallError {
     GetConfigs,
     GetCurrentContext,
     GetCurrentDisplay,
     GetDisplay,
+    GetPlatformDisplay,
     GetCurrentSurface,
     GetConfigAttrib,
     DestroySurface,
@@ -32,6 +37,9 @@ pub enum EglCallError {
     ChooseConfig,
     BindTexImage,
     BindAPI,
+    /// `eglBindAPI` failed with `EGL_BAD_PARAMETER`: the requested client API is
+    /// not supported by the EGL implementation.
+    BindAPIUnsupported,
     Terminate,
     WaitClient,
     WaitGL,
@@ -44,24 +52,242 @@ pub enum EglCallError {
     QuerySurface,
     QueryString,
     QueryContext,
+    CreateSync,
+    DestroySync,
+    ClientWaitSync,
+    WaitSync,
+    CreateSyncKHR,
+    DestroySyncKHR,
+    ClientWaitSyncKHR,
+    WaitSyncKHR,
+    DupNativeFenceFDANDROID,
+    QueryDmaBufFormatsEXT,
+    QueryDmaBufModifiersEXT,
+    QueryDisplayAttribEXT,
+    QueryDeviceStringEXT,
+    DebugMessageControlKHR,
+    LabelObjectKHR,
+    ExportDMABUFImageQueryMESA,
+    ExportDMABUFImageMESA,
+    CreateImage,
+    DestroyImage,
+    SetDamageRegionKHR,
+    GetNextFrameIdANDROID,
+    GetFrameTimestampsANDROID,
+    CreateStreamKHR,
+    DestroyStreamKHR,
+    StreamAttribKHR,
+    QueryStreamKHR,
+    QueryStreamu64KHR,
 }
 
+impl EglCallError {
+    /// Returns the name of the EGL C function that failed, such as
+    /// `"eglCreateWindowSurface"`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::error::EglCallError;
+    ///
+    /// let error = EglCallError::CreateWindowSurface;
+    /// assert_eq!(error.egl_function_name(), "eglCreateWindowSurface");
+    /// assert_eq!(error.to_string(), "eglCreateWindowSurface failed");
+    /// ```
+    pub fn egl_function_name(&self) -> &'static str {
+        match *self {
+            EglCallError::GetConfigs => "eglGetConfigs",
+            EglCallError::GetCurrentContext => "eglGetCurrentContext",
+            EglCallError::GetCurrentDisplay => "eglGetCurrentDisplay",
+            EglCallError::GetDisplay => "eglGetDisplay",
+            EglCallError::GetPlatformDisplay => "eglGetPlatformDisplay",
+            EglCallError::GetCurrentSurface => "eglGetCurrentSurface",
+            EglCallError::GetConfigAttrib => "eglGetConfigAttrib",
+            EglCallError::DestroySurface => "eglDestroySurface",
+            EglCallError::Initialize => "eglInitialize",
+            EglCallError::MakeCurrent => "eglMakeCurrent",
+            EglCallError::DestroyContext => "eglDestroyContext",
+            EglCallError::CreateWindowSurface => "eglCreateWindowSurface",
+            EglCallError::CreatePlatformWindowSurface => "eglCreatePlatformWindowSurface",
+            EglCallError::CreatePixmapSurface => "eglCreatePixmapSurface",
+            EglCallError::CreatePbufferSurface => "eglCreatePbufferSurface",
+            EglCallError::CreatePbufferFromClientBuffer => "eglCreatePbufferFromClientBuffer",
+            EglCallError::CreateContext => "eglCreateContext",
+            EglCallError::CopyBuffers => "eglCopyBuffers",
+            EglCallError::ChooseConfig => "eglChooseConfig",
+            EglCallError::BindTexImage => "eglBindTexImage",
+            EglCallError::BindAPI => "eglBindAPI",
+            EglCallError::BindAPIUnsupported => "eglBindAPI",
+            EglCallError::Terminate => "eglTerminate",
+            EglCallError::WaitClient => "eglWaitClient",
+            EglCallError::WaitGL => "eglWaitGL",
+            EglCallError::WaitNative => "eglWaitNative",
+            EglCallError::SwapInterval => "eglSwapInterval",
+            EglCallError::SwapBuffers => "eglSwapBuffers",
+            EglCallError::SurfaceAttrib => "eglSurfaceAttrib",
+            EglCallError::ReleaseThread => "eglReleaseThread",
+            EglCallError::ReleaseTexImage => "eglReleaseTexImage",
+            EglCallError::QuerySurface => "eglQuerySurface",
+            EglCallError::QueryString => "eglQueryString",
+            EglCallError::QueryContext => "eglQueryContext",
+            EglCallError::CreateSync => "eglCreateSync",
+            EglCallError::DestroySync => "eglDestroySync",
+            EglCallError::ClientWaitSync => "eglClientWaitSync",
+            EglCallError::WaitSync => "eglWaitSync",
+            EglCallError::CreateSyncKHR => "eglCreateSyncKHR",
+            EglCallError::DestroySyncKHR => "eglDestroySyncKHR",
+            EglCallError::ClientWaitSyncKHR => "eglClientWaitSyncKHR",
+            EglCallError::WaitSyncKHR => "eglWaitSyncKHR",
+            EglCallError::DupNativeFenceFDANDROID => "eglDupNativeFenceFDANDROID",
+            EglCallError::QueryDmaBufFormatsEXT => "eglQueryDmaBufFormatsEXT",
+            EglCallError::QueryDmaBufModifiersEXT => "eglQueryDmaBufModifiersEXT",
+            EglCallError::QueryDisplayAttribEXT => "eglQueryDisplayAttribEXT",
+            EglCallError::QueryDeviceStringEXT => "eglQueryDeviceStringEXT",
+            EglCallError::DebugMessageControlKHR => "eglDebugMessageControlKHR",
+            EglCallError::LabelObjectKHR => "eglLabelObjectKHR",
+            EglCallError::ExportDMABUFImageQueryMESA => "eglExportDMABUFImageQueryMESA",
+            EglCallError::ExportDMABUFImageMESA => "eglExportDMABUFImageMESA",
+            EglCallError::CreateImage => "eglCreateImage",
+            EglCallError::DestroyImage => "eglDestroyImage",
+            EglCallError::SetDamageRegionKHR => "eglSetDamageRegionKHR",
+            EglCallError::GetNextFrameIdANDROID => "eglGetNextFrameIdANDROID",
+            EglCallError::GetFrameTimestampsANDROID => "eglGetFrameTimestampsANDROID",
+            EglCallError::CreateStreamKHR => "eglCreateStreamKHR",
+            EglCallError::DestroyStreamKHR => "eglDestroyStreamKHR",
+            EglCallError::StreamAttribKHR => "eglStreamAttribKHR",
+            EglCallError::QueryStreamKHR => "eglQueryStreamKHR",
+            EglCallError::QueryStreamu64KHR => "eglQueryStreamu64KHR",
+        }
+    }
+}
+
+impl fmt::Display for EglCallError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EglCallError::BindAPIUnsupported => {
+                write!(f, "eglBindAPI failed: client API is not supported")
+            }
+            _ => write!(f, "{} failed", self.egl_function_name()),
+        }
+    }
+}
+
+impl error::Error for EglCallError {}
+
 pub type EglCallResult<T> = result::Result<T, EglCallError>;
 
-#[derive(Copy, Clone, Debug)]
+/// Errors returned by the wrappers in this crate.
+///
+/// Errors can be compared, so tests can assert a specific one:
+///
+/// ```
+/// use egli::error::{EglCallError, Error};
+///
+/// let error: Error = EglCallError::CreateContext.into();
+/// assert_eq!(error, Error::Egl(EglCallError::CreateContext));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Error {
     Egl(EglCallError),
     NonUtf8StringReceived(str::Utf8Error),
+    /// Objects passed to the same call belong to different displays.
+    MismatchedDisplay,
+    /// EGL returned a value that does not correspond to any known enum variant.
+    UnknownValue(i32),
+    /// The EGL extension required by the call is not supported.
+    ExtensionNotSupported(&'static str),
+    /// The config does not support window surfaces (`SurfaceType::WINDOW` is not set).
+    ConfigNotWindowCapable,
+    /// The config does not support pbuffer surfaces (`SurfaceType::PBUFFER` is not set).
+    ConfigNotPbufferCapable,
+    /// The `EGL_VERSION` string does not start with a `major.minor` version.
+    InvalidVersionString(VersionParseError),
+    /// The config does not support the client API required by the call. Contains the
+    /// missing `RenderableType` bits.
+    ConfigLacksRenderableType(RenderableType),
+    /// The requested frame timestamps are not available yet.
+    FrameTimestampsPending,
+    /// The window handle kind can not be used to create an EGL window surface
+    /// directly.
+    UnsupportedWindowHandle,
+    /// The config does not support preserving the color buffer after swapping
+    /// (`SurfaceType::SWAP_BEHAVIOR_PRESERVED` is not set).
+    ConfigNotSwapPreservedCapable,
+    /// An EGL function loaded at runtime, such as an extension function, is not
+    /// available from `eglGetProcAddress`.
+    SymbolNotFound(&'static str),
+    /// EGL reported more configs than the buffer passed to it could hold.
+    /// Contains the reported count.
+    TooManyConfigsReturned(usize),
+    /// The context is not current to the calling thread with the required surface.
+    ContextNotCurrent,
+    /// The display was not initialized with `Display::initialize`, or was terminated,
+    /// before getting configs or creating surfaces or contexts on it.
+    DisplayNotInitialized,
+    /// The surface handle is not a valid EGL surface, for example because it was
+    /// destroyed after `Surface::into_raw`.
+    BadSurface,
+    /// A debug callback panicked while the debug callback state was locked, so the
+    /// state may be inconsistent.
+    DebugStatePoisoned,
 }
 
 pub type Result<T> = result::Result<T, Error>;
 
+/// Converts an `Option` returned by a manually composed EGL call into a `Result`.
+///
+/// ## Example
+///
+/// ```
+/// use egli::error::{EglCallError, OptionExt, Result};
+///
+/// fn first_config(configs: &[i32]) -> Result<i32> {
+///     configs.first().cloned().ok_or_egl(EglCallError::ChooseConfig)
+/// }
+///
+/// assert_eq!(first_config(&[7]).ok(), Some(7));
+/// assert!(first_config(&[]).is_err());
+/// ```
+pub trait OptionExt<T> {
+    /// Returns the contained value, or `Error::Egl(err)` if it is `None`.
+    fn ok_or_egl(self, err: EglCallError) -> Result<T>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn ok_or_egl(self, err: EglCallError) -> Result<T> {
+        self.ok_or(Error::Egl(err))
+    }
+}
+
+impl Error {
+    /// Converts a failed EGL call to `Error::DisplayNotInitialized` if EGL reports
+    /// `EGL_NOT_INITIALIZED`, which happens on a display that was never initialized or
+    /// was terminated, and to `Error::BadSurface` if EGL reports `EGL_BAD_SURFACE`.
+    /// Otherwise returns `Error::Egl(err)`.
+    ///
+    /// Reads and clears the EGL error of the calling thread, so it must be called right
+    /// after the failed call.
+    pub(crate) fn from_failed_call(err: EglCallError) -> Error {
+        match egl::get_error() {
+            egl::error_code::EGL_NOT_INITIALIZED => Error::DisplayNotInitialized,
+            egl::error_code::EGL_BAD_SURFACE => Error::BadSurface,
+            _ => Error::Egl(err),
+        }
+    }
+}
+
 impl From<EglCallError> for Error {
     fn from(other: EglCallError) -> Error {
         Error::Egl(other)
     }
 }
 
+impl From<VersionParseError> for Error {
+    fn from(other: VersionParseError) -> Error {
+        Error::InvalidVersionString(other)
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     fn from(other: str::Utf8Error) -> Error {
         Error::NonUtf8StringReceived(other)
@@ -6,10 +6,17 @@
 use libc::{c_char, c_void};
 
 use egl::{EGLBoolean, EGLClientBuffer, EGLConfig, EGLContext, EGLDisplay, EGLenum, EGLint,
-          EGLNativeDisplayType, EGLNativePixmapType, EGLNativeWindowType, EGLSurface};
+          EGLNativeDisplayType, EGLNativePixmapType, EGLNativeWindowType, EGLSurface,
+          EGLSyncKHR, EGLTimeKHR, EGLuint64KHR, EGLnsecsANDROID, EGLDeviceEXT, EGLAttrib};
 
-#[cfg(feature = "egl_1_5")]
-use egl::{EGLSync, EGLAttrib, EGLImage, EGLTime};
+#[cfg(egl_has_1_5)]
+use egl::{EGLSync, EGLImage, EGLTime};
+
+#[cfg(feature = "egl_stream")]
+use egl::EGLStreamKHR;
+
+#[cfg(feature = "egl_debug")]
+use egl::{EGLLabelKHR, EGLObjectKHR};
 
 extern "C" {
     pub fn eglChooseConfig(dpy: EGLDisplay,
@@ -140,30 +147,30 @@ extern "C" {
 
     // EGL 1.5
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglCreateSync(dpy: EGLDisplay,
                          _type: EGLenum,
                          attrib_list: *const EGLAttrib)
                          -> EGLSync;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglDestroySync(dpy: EGLDisplay, sync: EGLSync) -> EGLBoolean;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglClientWaitSync(dpy: EGLDisplay,
                              sync: EGLSync,
                              flags: EGLint,
                              timeout: EGLTime)
                              -> EGLint;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglGetSyncAttrib(dpy: EGLDisplay,
                             sync: EGLSync,
                             attribute: EGLint,
                             value: *mut EGLAttrib)
                             -> EGLBoolean;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglCreateImage(dpy: EGLDisplay,
                           ctx: EGLContext,
                           target: EGLenum,
@@ -171,29 +178,184 @@ extern "C" {
                           attrib_list: *const EGLAttrib)
                           -> EGLImage;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglDestroyImage(dpy: EGLDisplay, image: EGLImage) -> EGLBoolean;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglGetPlatformDisplay(platform: EGLenum,
                                  native_display: *mut c_void,
                                  attrib_list: *const EGLAttrib)
                                  -> EGLDisplay;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglCreatePlatformWindowSurface(dpy: EGLDisplay,
                                           config: EGLConfig,
                                           native_window: *mut c_void,
                                           attrib_list: *const EGLAttrib)
                                           -> EGLSurface;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglCreatePlatformPixmapSurface(dpy: EGLDisplay,
                                           config: EGLConfig,
                                           native_pixmap: *mut c_void,
                                           attrib_list: *const EGLAttrib)
                                           -> EGLSurface;
 
-    #[cfg(feature = "egl_1_5")]
+    #[cfg(egl_has_1_5)]
     pub fn eglWaitSync(dpy: EGLDisplay, sync: EGLSync, flags: EGLint) -> EGLBoolean;
 }
+
+// Extension functions are not exported by the EGL library and must be loaded at runtime
+// with `eglGetProcAddress`. These are the types of loaded function pointers.
+
+// EGL_KHR_fence_sync
+
+pub type PFNEGLCREATESYNCKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                        _type: EGLenum,
+                                                        attrib_list: *const EGLint)
+                                                        -> EGLSyncKHR;
+
+pub type PFNEGLDESTROYSYNCKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay, sync: EGLSyncKHR)
+                                                         -> EGLBoolean;
+
+pub type PFNEGLCLIENTWAITSYNCKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                            sync: EGLSyncKHR,
+                                                            flags: EGLint,
+                                                            timeout: EGLTimeKHR)
+                                                            -> EGLint;
+
+// EGL_KHR_wait_sync
+
+pub type PFNEGLWAITSYNCKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                      sync: EGLSyncKHR,
+                                                      flags: EGLint)
+                                                      -> EGLint;
+
+// EGL_ANDROID_native_fence_sync
+
+pub type PFNEGLDUPNATIVEFENCEFDANDROIDPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                  sync: EGLSyncKHR)
+                                                                  -> EGLint;
+
+// EGL_EXT_image_dma_buf_import_modifiers
+
+pub type PFNEGLQUERYDMABUFFORMATSEXTPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                max_formats: EGLint,
+                                                                formats: *mut EGLint,
+                                                                num_formats: *mut EGLint)
+                                                                -> EGLBoolean;
+
+pub type PFNEGLQUERYDMABUFMODIFIERSEXTPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                  format: EGLint,
+                                                                  max_modifiers: EGLint,
+                                                                  modifiers: *mut EGLuint64KHR,
+                                                                  external_only: *mut EGLBoolean,
+                                                                  num_modifiers: *mut EGLint)
+                                                                  -> EGLBoolean;
+
+// EGL_EXT_device_query
+
+pub type PFNEGLQUERYDISPLAYATTRIBEXTPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                attribute: EGLint,
+                                                                value: *mut EGLAttrib)
+                                                                -> EGLBoolean;
+
+pub type PFNEGLQUERYDEVICESTRINGEXTPROC = unsafe extern "C" fn(device: EGLDeviceEXT,
+                                                               name: EGLint)
+                                                               -> *const c_char;
+
+// EGL_KHR_debug
+
+#[cfg(feature = "egl_debug")]
+pub type EGLDEBUGPROCKHR = Option<unsafe extern "C" fn(error: EGLenum,
+                                                       command: *const c_char,
+                                                       message_type: EGLint,
+                                                       thread_label: EGLLabelKHR,
+                                                       object_label: EGLLabelKHR,
+                                                       message: *const c_char)>;
+
+#[cfg(feature = "egl_debug")]
+pub type PFNEGLDEBUGMESSAGECONTROLKHRPROC =
+    unsafe extern "C" fn(callback: EGLDEBUGPROCKHR, attrib_list: *const EGLAttrib)
+                         -> EGLint;
+
+#[cfg(feature = "egl_debug")]
+pub type PFNEGLLABELOBJECTKHRPROC = unsafe extern "C" fn(display: EGLDisplay,
+                                                         object_type: EGLenum,
+                                                         object: EGLObjectKHR,
+                                                         label: EGLLabelKHR)
+                                                         -> EGLint;
+
+// EGL_MESA_image_dma_buf_export
+
+#[cfg(all(egl_has_1_5, feature = "egl_dmabuf_export"))]
+pub type PFNEGLEXPORTDMABUFIMAGEQUERYMESAPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                     image: EGLImage,
+                                                                     fourcc: *mut EGLint,
+                                                                     num_planes: *mut EGLint,
+                                                                     modifiers: *mut EGLuint64KHR)
+                                                                     -> EGLBoolean;
+
+#[cfg(all(egl_has_1_5, feature = "egl_dmabuf_export"))]
+pub type PFNEGLEXPORTDMABUFIMAGEMESAPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                image: EGLImage,
+                                                                fds: *mut EGLint,
+                                                                strides: *mut EGLint,
+                                                                offsets: *mut EGLint)
+                                                                -> EGLBoolean;
+
+// EGL_ANDROID_get_frame_timestamps
+
+pub type PFNEGLGETNEXTFRAMEIDANDROIDPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                surface: EGLSurface,
+                                                                frame_id: *mut EGLuint64KHR)
+                                                                -> EGLBoolean;
+
+pub type PFNEGLGETFRAMETIMESTAMPSANDROIDPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                                    surface: EGLSurface,
+                                                                    frame_id: EGLuint64KHR,
+                                                                    num_timestamps: EGLint,
+                                                                    timestamps: *const EGLint,
+                                                                    values: *mut EGLnsecsANDROID)
+                                                                    -> EGLBoolean;
+
+// EGL_KHR_partial_update
+
+pub type PFNEGLSETDAMAGEREGIONKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                             surface: EGLSurface,
+                                                             rects: *mut EGLint,
+                                                             n_rects: EGLint)
+                                                             -> EGLBoolean;
+
+// EGL_KHR_stream
+
+#[cfg(feature = "egl_stream")]
+pub type PFNEGLCREATESTREAMKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                          attrib_list: *const EGLint)
+                                                          -> EGLStreamKHR;
+
+#[cfg(feature = "egl_stream")]
+pub type PFNEGLDESTROYSTREAMKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                           stream: EGLStreamKHR)
+                                                           -> EGLBoolean;
+
+#[cfg(feature = "egl_stream")]
+pub type PFNEGLSTREAMATTRIBKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                          stream: EGLStreamKHR,
+                                                          attribute: EGLenum,
+                                                          value: EGLint)
+                                                          -> EGLBoolean;
+
+#[cfg(feature = "egl_stream")]
+pub type PFNEGLQUERYSTREAMKHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                         stream: EGLStreamKHR,
+                                                         attribute: EGLenum,
+                                                         value: *mut EGLint)
+                                                         -> EGLBoolean;
+
+#[cfg(feature = "egl_stream")]
+pub type PFNEGLQUERYSTREAMU64KHRPROC = unsafe extern "C" fn(dpy: EGLDisplay,
+                                                            stream: EGLStreamKHR,
+                                                            attribute: EGLenum,
+                                                            value: *mut EGLuint64KHR)
+                                                            -> EGLBoolean;
@@ -6,8 +6,10 @@
 // copied, modified, or distributed except according to those terms.
 
 use egl;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt;
-use error::Result;
+use error::{Error, Result};
 use {ColorBufferType, ConfigCaveat, RenderableType, SurfaceType, TransparentType};
 
 /// `[EGL 1.0]` Reference to frame buffer configuration.
@@ -16,12 +18,28 @@ use {ColorBufferType, ConfigCaveat, RenderableType, SurfaceType, TransparentType
 ///
 /// Instead, the individual methods of this object may return errors if they are used
 /// after the display is terminated.
+///
+/// Configs are ordered by `quality_key`, so `configs.iter().max()` picks a reasonable
+/// default. Two refs are equal if they refer to the same config of the same display.
 #[derive(Copy, Clone)]
 pub struct FrameBufferConfigRef {
     display_handle: egl::EGLDisplay,
     config_handle: egl::EGLConfig,
 }
 
+/// Validates the number of configs written by `eglGetConfigs` or `eglChooseConfig`
+/// into a buffer of `buffer_len` handles.
+///
+/// The count is expected to never exceed the buffer size, but some drivers report more
+/// configs than the buffer held.
+pub(crate) fn returned_config_count(returned: i32, buffer_len: usize) -> Result<usize> {
+    let returned = if returned < 0 { 0 } else { returned as usize };
+    if returned > buffer_len {
+        return Err(Error::TooManyConfigsReturned(returned));
+    }
+    Ok(returned)
+}
+
 impl FrameBufferConfigRef {
     pub fn from_native(display_id: egl::EGLDisplay,
                        config_handle: egl::EGLConfig)
@@ -44,7 +62,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_ALPHA_SIZE` attribute.
     pub fn alpha_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_ALPHA_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_ALPHA_SIZE)
             .map(|v| v as u32)
     }
 
@@ -52,7 +70,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_ALPHA_MASK_SIZE` attribute.
     pub fn alpha_mask_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_ALPHA_MASK_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_ALPHA_MASK_SIZE)
             .map(|v| v as u32)
     }
 
@@ -60,7 +78,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_BIND_TO_TEXTURE_RGB` attribute.
     pub fn bind_to_texture_rgb(&self) -> Result<bool> {
-        self.get_attrib(egl::EGL_BIND_TO_TEXTURE_RGB)
+        self.get_attrib(egl::config_attrib::EGL_BIND_TO_TEXTURE_RGB)
             .map(|v| (v as egl::EGLBoolean) == egl::EGL_TRUE)
     }
 
@@ -68,7 +86,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_BIND_TO_TEXTURE_RGBA` attribute.
     pub fn bind_to_texture_rgba(&self) -> Result<bool> {
-        self.get_attrib(egl::EGL_BIND_TO_TEXTURE_RGBA)
+        self.get_attrib(egl::config_attrib::EGL_BIND_TO_TEXTURE_RGBA)
             .map(|v| (v as egl::EGLBoolean) == egl::EGL_TRUE)
     }
 
@@ -76,7 +94,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_BLUE_SIZE` attribute.
     pub fn blue_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_BLUE_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_BLUE_SIZE)
             .map(|v| v as u32)
     }
 
@@ -86,7 +104,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_BUFFER_SIZE` attribute.
     pub fn buffer_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_BUFFER_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_BUFFER_SIZE)
             .map(|v| v as u32)
     }
 
@@ -95,8 +113,8 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_COLOR_BUFFER_TYPE` attribute.
     pub fn color_buffer_type(&self) -> Result<ColorBufferType> {
-        self.get_attrib(egl::EGL_COLOR_BUFFER_TYPE)
-            .map(|value| unsafe { ColorBufferType::from_raw(value) })
+        self.get_attrib(egl::config_attrib::EGL_COLOR_BUFFER_TYPE)
+            .and_then(ColorBufferType::try_from)
     }
 
     /// Returns the caveats for the frame buffer configuration.
@@ -104,15 +122,15 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_CONFIG_CAVEAT` attribute.
     pub fn config_caveat(&self) -> Result<ConfigCaveat> {
-        self.get_attrib(egl::EGL_CONFIG_CAVEAT)
-            .map(|value| unsafe { ConfigCaveat::from_raw(value) })
+        self.get_attrib(egl::config_attrib::EGL_CONFIG_CAVEAT)
+            .and_then(ConfigCaveat::try_from)
     }
 
     /// Returns the ID of the frame buffer configuration.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_CONFIG_ID` attribute.
     pub fn config_id(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_CONFIG_ID)
+        self.get_attrib(egl::config_attrib::EGL_CONFIG_ID)
     }
 
     /// Returns a bitmask indicating which client API contexts created with respect to
@@ -120,17 +138,27 @@ impl FrameBufferConfigRef {
     ///
     /// EGL_CONFORMANT is supported only if the EGL version is 1.3 or greater.
     ///
+    /// Bits unknown to `RenderableType` are dropped; use `conformant_raw` to keep them.
+    ///
     /// Calls `eglGetConfigAttrib` with `EGL_CONFORMANT` attribute.
     pub fn conformant(&self) -> Result<RenderableType> {
-        self.get_attrib(egl::EGL_CONFORMANT)
+        self.get_attrib(egl::config_attrib::EGL_CONFORMANT)
             .map(|v| RenderableType::from_bits_truncate(v))
     }
 
+    /// Returns the raw `EGL_CONFORMANT` bitmask, including bits unknown to
+    /// `RenderableType`.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_CONFORMANT` attribute.
+    pub fn conformant_raw(&self) -> Result<egl::EGLint> {
+        self.get_attrib(egl::config_attrib::EGL_CONFORMANT)
+    }
+
     /// Returns the number of bits in the depth buffer.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_DEPTH_SIZE` attribute.
     pub fn depth_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_DEPTH_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_DEPTH_SIZE)
             .map(|v| v as u32)
     }
 
@@ -138,7 +166,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_GREEN_SIZE` attribute.
     pub fn green_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_GREEN_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_GREEN_SIZE)
             .map(|v| v as u32)
     }
 
@@ -149,57 +177,84 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_LEVEL` attribute.
     pub fn level(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_LEVEL)
+        self.get_attrib(egl::config_attrib::EGL_LEVEL)
     }
 
     /// Returns the number of bits of luminance stored in the luminance buffer.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_LUMINANCE_SIZE` attribute.
     pub fn luminance_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_LUMINANCE_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_LUMINANCE_SIZE)
             .map(|v| v as u32)
     }
 
     /// Returns the maximum width of a pixel buffer surface in pixels.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_MAX_PBUFFER_WIDTH` attribute.
+    #[deprecated(note = "use `FrameBufferConfigRef::max_pbuffer_width_u32`")]
     pub fn max_pbuffer_width(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_MAX_PBUFFER_WIDTH)
+        self.get_attrib(egl::config_attrib::EGL_MAX_PBUFFER_WIDTH)
+    }
+
+    /// Returns the maximum width of a pixel buffer surface in pixels.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_MAX_PBUFFER_WIDTH` attribute.
+    pub fn max_pbuffer_width_u32(&self) -> Result<u32> {
+        self.get_attrib(egl::config_attrib::EGL_MAX_PBUFFER_WIDTH)
+            .map(|v| v as u32)
     }
 
     /// Returns the maximum height of a pixel buffer surface in pixels.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_MAX_PBUFFER_HEIGHT` attribute.
+    #[deprecated(note = "use `FrameBufferConfigRef::max_pbuffer_height_u32`")]
     pub fn max_pbuffer_height(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_MAX_PBUFFER_HEIGHT)
+        self.get_attrib(egl::config_attrib::EGL_MAX_PBUFFER_HEIGHT)
+    }
+
+    /// Returns the maximum height of a pixel buffer surface in pixels.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_MAX_PBUFFER_HEIGHT` attribute.
+    pub fn max_pbuffer_height_u32(&self) -> Result<u32> {
+        self.get_attrib(egl::config_attrib::EGL_MAX_PBUFFER_HEIGHT)
+            .map(|v| v as u32)
     }
 
     /// Returns the maximum size of a pixel buffer surface in pixels.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_MAX_PBUFFER_PIXELS` attribute.
+    #[deprecated(note = "use `FrameBufferConfigRef::max_pbuffer_pixels_u32`")]
     pub fn max_pbuffer_pixels(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_MAX_PBUFFER_PIXELS)
+        self.get_attrib(egl::config_attrib::EGL_MAX_PBUFFER_PIXELS)
+    }
+
+    /// Returns the maximum size of a pixel buffer surface in pixels.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_MAX_PBUFFER_PIXELS` attribute.
+    pub fn max_pbuffer_pixels_u32(&self) -> Result<u32> {
+        self.get_attrib(egl::config_attrib::EGL_MAX_PBUFFER_PIXELS)
+            .map(|v| v as u32)
     }
 
     /// Returns the maximum value that can be passed to eglSwapInterval.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_MAX_SWAP_INTERVAL` attribute.
     pub fn max_swap_interval(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_MAX_SWAP_INTERVAL)
+        self.get_attrib(egl::config_attrib::EGL_MAX_SWAP_INTERVAL)
     }
 
     /// Returns the minimum value that can be passed to eglSwapInterval.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_MIN_SWAP_INTERVAL` attribute.
     pub fn min_swap_interval(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_MIN_SWAP_INTERVAL)
+        self.get_attrib(egl::config_attrib::EGL_MIN_SWAP_INTERVAL)
     }
 
     /// Returns whether native rendering APIs can render into the surface.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_NATIVE_RENDERABLE` attribute.
     pub fn native_renderable(&self) -> Result<bool> {
-        self.get_attrib(egl::EGL_NATIVE_RENDERABLE)
+        self.get_attrib(egl::config_attrib::EGL_NATIVE_RENDERABLE)
             .map(|v| (v as egl::EGLBoolean) == egl::EGL_TRUE)
     }
 
@@ -207,76 +262,138 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_NATIVE_VISUAL_ID` attribute.
     pub fn native_visual_id(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_NATIVE_VISUAL_ID)
+        self.get_attrib(egl::config_attrib::EGL_NATIVE_VISUAL_ID)
     }
 
     /// Returns the type of the associated native visual.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_NATIVE_VISUAL_TYPE` attribute.
     pub fn native_visual_type(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_NATIVE_VISUAL_TYPE)
+        self.get_attrib(egl::config_attrib::EGL_NATIVE_VISUAL_TYPE)
     }
 
     /// Returns the number of bits of red stored in the color buffer.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_RED_SIZE` attribute.
     pub fn red_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_RED_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_RED_SIZE)
             .map(|v| v as u32)
     }
 
     /// Returns a bitmask indicating the types of supported client API contexts.
     ///
+    /// Bits unknown to `RenderableType`, such as client APIs added by newer drivers,
+    /// are dropped; use `renderable_type_raw` to keep them.
+    ///
     /// Calls `eglGetConfigAttrib` with `EGL_RENDERABLE_TYPE` attribute.
     pub fn renderable_type(&self) -> Result<RenderableType> {
-        self.get_attrib(egl::EGL_RENDERABLE_TYPE)
+        self.get_attrib(egl::config_attrib::EGL_RENDERABLE_TYPE)
             .map(|v| RenderableType::from_bits_truncate(v))
     }
 
+    /// Returns the raw `EGL_RENDERABLE_TYPE` bitmask, including bits unknown to
+    /// `RenderableType`.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_RENDERABLE_TYPE` attribute.
+    pub fn renderable_type_raw(&self) -> Result<egl::EGLint> {
+        self.get_attrib(egl::config_attrib::EGL_RENDERABLE_TYPE)
+    }
+
     /// Returns the number of multisample buffers.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_SAMPLE_BUFFERS` attribute.
     pub fn sample_buffers(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_SAMPLE_BUFFERS)
+        self.get_attrib(egl::config_attrib::EGL_SAMPLE_BUFFERS)
     }
 
     /// Returns the number of samples per pixel.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_SAMPLES` attribute.
     pub fn samples(&self) -> Result<i32> {
-        self.get_attrib(egl::EGL_SAMPLES)
+        self.get_attrib(egl::config_attrib::EGL_SAMPLES)
     }
 
     /// Returns the number of bits in the stencil buffer.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_STENCIL_SIZE` attribute.
     pub fn stencil_size(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_STENCIL_SIZE)
+        self.get_attrib(egl::config_attrib::EGL_STENCIL_SIZE)
             .map(|v| v as u32)
     }
 
     /// Returns a bitmask indicating the types of supported EGL surfaces.
     ///
+    /// Bits unknown to `SurfaceType`, such as those added by extensions, are dropped;
+    /// use `surface_type_raw` to keep them.
+    ///
     /// Calls `eglGetConfigAttrib` with `EGL_SURFACE_TYPE` attribute.
     pub fn surface_type(&self) -> Result<SurfaceType> {
-        self.get_attrib(egl::EGL_SURFACE_TYPE)
+        self.get_attrib(egl::config_attrib::EGL_SURFACE_TYPE)
             .map(|v| SurfaceType::from_bits_truncate(v))
     }
 
+    /// Returns the raw `EGL_SURFACE_TYPE` bitmask, including bits unknown to
+    /// `SurfaceType`.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_SURFACE_TYPE` attribute.
+    pub fn surface_type_raw(&self) -> Result<egl::EGLint> {
+        self.get_attrib(egl::config_attrib::EGL_SURFACE_TYPE)
+    }
+
+    /// Returns `true` if this config can back a window surface rendered with all of
+    /// the client APIs in `api`.
+    ///
+    /// Checks `SurfaceType::WINDOW` bit of `surface_type`, and `api` bits of
+    /// `renderable_type`. The `conformant` bits are not checked.
+    pub fn is_suitable_for_window(&self, api: RenderableType) -> Result<bool> {
+        Ok(self.surface_type()?.contains(SurfaceType::WINDOW) &&
+           self.renderable_type()?.contains(api))
+    }
+
+    /// Returns `true` if this config can back a pbuffer surface rendered with all of
+    /// the client APIs in `api`.
+    ///
+    /// Checks `SurfaceType::PBUFFER` bit of `surface_type`, and `api` bits of
+    /// `renderable_type`. The `conformant` bits are not checked.
+    pub fn is_suitable_for_pbuffer(&self, api: RenderableType) -> Result<bool> {
+        Ok(self.surface_type()?.contains(SurfaceType::PBUFFER) &&
+           self.renderable_type()?.contains(api))
+    }
+
+    /// Returns `true` if surfaces created with this config can preserve the color buffer
+    /// contents after swapping, i.e. `EGL_SWAP_BEHAVIOR` can be set to
+    /// `EGL_BUFFER_PRESERVED`.
+    ///
+    /// Checks `SurfaceType::SWAP_BEHAVIOR_PRESERVED` bit of `surface_type`.
+    pub fn supports_preserved_swap(&self) -> Result<bool> {
+        self.surface_type()
+            .map(|v| v.contains(SurfaceType::SWAP_BEHAVIOR_PRESERVED))
+    }
+
+    /// Returns `true` if surfaces created with this config can use box filtered
+    /// multisample resolve, i.e. `EGL_MULTISAMPLE_RESOLVE` can be set to
+    /// `EGL_MULTISAMPLE_RESOLVE_BOX`.
+    ///
+    /// Checks `SurfaceType::MULTISAMPLE_RESOLVE_BOX` bit of `surface_type`.
+    pub fn supports_multisample_resolve_box(&self) -> Result<bool> {
+        self.surface_type()
+            .map(|v| v.contains(SurfaceType::MULTISAMPLE_RESOLVE_BOX))
+    }
+
     /// Returns the type of supported transparency.
     /// Possible transparency values are: EGL_NONE, and EGL_TRANSPARENT_RGB.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_TRANSPARENT_TYPE` attribute.
     pub fn transparent_type(&self) -> Result<TransparentType> {
-        self.get_attrib(egl::EGL_TRANSPARENT_TYPE)
-            .map(|value| unsafe { TransparentType::from_raw(value) })
+        self.get_attrib(egl::config_attrib::EGL_TRANSPARENT_TYPE)
+            .and_then(TransparentType::try_from)
     }
 
     /// Returns the transparent red value.
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_TRANSPARENT_RED_VALUE` attribute.
     pub fn transparent_red_value(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_TRANSPARENT_RED_VALUE)
+        self.get_attrib(egl::config_attrib::EGL_TRANSPARENT_RED_VALUE)
             .map(|value| value as u32)
     }
 
@@ -284,7 +401,7 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_TRANSPARENT_GREEN_VALUE` attribute.
     pub fn transparent_green_value(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_TRANSPARENT_GREEN_VALUE)
+        self.get_attrib(egl::config_attrib::EGL_TRANSPARENT_GREEN_VALUE)
             .map(|value| value as u32)
     }
 
@@ -292,16 +409,54 @@ impl FrameBufferConfigRef {
     ///
     /// Calls `eglGetConfigAttrib` with `EGL_TRANSPARENT_BLUE_VALUE` attribute.
     pub fn transparent_blue_value(&self) -> Result<u32> {
-        self.get_attrib(egl::EGL_TRANSPARENT_GREEN_VALUE)
+        self.get_attrib(egl::config_attrib::EGL_TRANSPARENT_GREEN_VALUE)
             .map(|value| value as u32)
     }
 
+    /// `[EGL_EXT_config_select_group]` Returns the selection group of the config.
+    ///
+    /// `eglChooseConfig` sorts configs by their selection group before applying the
+    /// usual sorting rules, so configs in a lower group always come first. Returns
+    /// `None` if the query fails, which happens when the extension is not supported.
+    ///
+    /// Calls `eglGetConfigAttrib` with `EGL_CONFIG_SELECT_GROUP_EXT` attribute.
+    pub fn select_group(&self) -> Result<Option<i32>> {
+        Ok(self.get_attrib(egl::config_attrib::EGL_CONFIG_SELECT_GROUP_EXT).ok())
+    }
+
+    /// Returns the key used to order configs by quality, from worst to best.
+    ///
+    /// Compares, in order:
+    ///
+    /// - the number of client APIs the config is renderable with (`renderable_type`),
+    /// - the color depth (`buffer_size`),
+    /// - the number of samples per pixel (`samples`),
+    /// - the `config_id`.
+    ///
+    /// An attribute that can not be queried is `None`, which is lower than any value.
+    ///
+    /// Each call queries the attributes with `eglGetConfigAttrib`, and so does every
+    /// comparison of two configs. To sort a long list, query the key once per config:
+    ///
+    /// ```no_run
+    /// # fn example(mut configs: Vec<egli::FrameBufferConfigRef>) {
+    /// configs.sort_by_cached_key(|config| config.quality_key());
+    /// let best = configs.last();
+    /// # }
+    /// ```
+    pub fn quality_key(&self) -> (Option<u32>, Option<u32>, Option<i32>, Option<i32>) {
+        (self.renderable_type_raw().ok().map(|v| v.count_ones()),
+         self.buffer_size().ok(),
+         self.samples().ok(),
+         self.config_id().ok())
+    }
+
     fn get_attrib(&self, attribute: egl::EGLint) -> Result<egl::EGLint> {
         let mut value: egl::EGLint = 0;
         egl::get_config_attrib(self.display_handle,
                                     self.config_handle,
                                     attribute,
-                                    &mut value)?;
+                                    &mut value).map_err(Error::from_failed_call)?;
         Ok(value)
     }
 
@@ -323,9 +478,9 @@ impl FrameBufferConfigRef {
             .field("conformant", &self.conformant()?)
             .field("level", &self.level()?)
             .field("luminance_size", &self.luminance_size()?)
-            .field("max_pbuffer_width", &self.max_pbuffer_width()?)
-            .field("max_pbuffer_height", &self.max_pbuffer_height()?)
-            .field("max_pbuffer_pixels", &self.max_pbuffer_pixels()?)
+            .field("max_pbuffer_width", &self.max_pbuffer_width_u32()?)
+            .field("max_pbuffer_height", &self.max_pbuffer_height_u32()?)
+            .field("max_pbuffer_pixels", &self.max_pbuffer_pixels_u32()?)
             .field("max_swap_interval", &self.max_swap_interval()?)
             .field("min_swap_interval", &self.min_swap_interval()?)
             .field("native_renderable", &self.native_renderable()?)
@@ -345,6 +500,38 @@ impl FrameBufferConfigRef {
     }
 }
 
+impl PartialEq for FrameBufferConfigRef {
+    fn eq(&self, other: &FrameBufferConfigRef) -> bool {
+        self.display_handle == other.display_handle &&
+        self.config_handle == other.config_handle
+    }
+}
+
+impl Eq for FrameBufferConfigRef {}
+
+impl PartialOrd for FrameBufferConfigRef {
+    fn partial_cmp(&self, other: &FrameBufferConfigRef) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders configs by `quality_key`, calling `eglGetConfigAttrib` for both configs
+/// on every comparison.
+///
+/// Configs with equal keys are ordered by their handles, so only the same config
+/// compares as equal.
+impl Ord for FrameBufferConfigRef {
+    fn cmp(&self, other: &FrameBufferConfigRef) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        self.quality_key()
+            .cmp(&other.quality_key())
+            .then((self.display_handle as usize).cmp(&(other.display_handle as usize)))
+            .then((self.config_handle as usize).cmp(&(other.config_handle as usize)))
+    }
+}
+
 impl fmt::Debug for FrameBufferConfigRef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.format_debug_struct(f) {
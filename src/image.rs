@@ -0,0 +1,127 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::rc::Rc;
+#[cfg(all(unix, feature = "egl_dmabuf_export"))]
+use std::os::unix::io::RawFd;
+use egl;
+#[cfg(all(unix, feature = "egl_dmabuf_export"))]
+use error::Result;
+
+/// `[EGL_MESA_image_dma_buf_export]` Planes of an `Image` exported as dmabuf, returned
+/// by `Image::export_dmabuf`.
+///
+/// All vectors have one entry per plane. The file descriptors are owned by the caller,
+/// who is responsible for closing them.
+#[cfg(all(unix, feature = "egl_dmabuf_export"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DmabufExport {
+    /// DRM fourcc format of the buffer.
+    pub fourcc: u32,
+    /// Format modifier of each plane.
+    pub modifiers: Vec<u64>,
+    /// File descriptor of each plane.
+    pub fds: Vec<RawFd>,
+    /// Pitch of each plane in bytes.
+    pub strides: Vec<u32>,
+    /// Offset of each plane in bytes.
+    pub offsets: Vec<u32>,
+}
+
+/// `[EGL 1.5]` [RAII](https://en.wikipedia.org/wiki/Resource_Acquisition_Is_Initialization) wrapper for
+/// EGLImage.
+///
+/// When dropped, frees up the image with `eglDestroyImage` call.
+///
+/// The image can be bound to a client API texture with the raw handle, for example
+/// with `glEGLImageTargetTexture2DOES`.
+#[must_use = "dropping an `Image` destroys it immediately"]
+pub struct Image {
+    terminated: bool,
+    display_handle: egl::EGLDisplay,
+    handle: egl::EGLImage,
+    display_token: Option<Rc<()>>,
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        if !self.terminated {
+            let _ = egl::destroy_image(self.display_handle, self.handle);
+        }
+    }
+}
+
+impl Into<egl::EGLImage> for Image {
+    fn into(self) -> egl::EGLImage {
+        self.into_raw()
+    }
+}
+
+impl Image {
+    /// Create an `Image` from an existing EGL display and image handles.
+    ///
+    /// The created `Image` takes ownership of the handle and destroys it when dropped.
+    pub fn from_raw(display_handle: egl::EGLDisplay, image_handle: egl::EGLImage) -> Image {
+        Image {
+            terminated: false,
+            display_handle,
+            handle: image_handle,
+            display_token: None,
+        }
+    }
+
+    /// Keeps a token of the `Display` that created this object, so the display
+    /// can detect that it is dropped too early.
+    pub(crate) fn with_display_token(mut self, token: Rc<()>) -> Image {
+        self.display_token = Some(token);
+        self
+    }
+
+    /// Get raw handle.
+    pub fn handle(&self) -> egl::EGLImage {
+        self.handle
+    }
+
+    /// Get raw handle of the display this image was created on.
+    pub fn display_handle(&self) -> egl::EGLDisplay {
+        self.display_handle
+    }
+
+    /// `[EGL_MESA_image_dma_buf_export]` Export this image as dmabuf, to share it with
+    /// another process or API without copying.
+    ///
+    /// The image is usually created from a client API texture. Check
+    /// `display.has_extension("EGL_MESA_image_dma_buf_export")` before using it; if the
+    /// functions are not available, this fails with `Error::SymbolNotFound`.
+    ///
+    /// Only available with the `egl_dmabuf_export` feature.
+    #[cfg(all(unix, feature = "egl_dmabuf_export"))]
+    pub fn export_dmabuf(&self) -> Result<DmabufExport> {
+        let (fourcc, modifiers) =
+            egl::export_dmabuf_image_query_mesa(self.display_handle, self.handle)?;
+        let (fds, strides, offsets) =
+            egl::export_dmabuf_image_mesa(self.display_handle, self.handle)?;
+
+        Ok(DmabufExport {
+            fourcc: fourcc as u32,
+            modifiers,
+            fds,
+            strides: strides.into_iter().map(|v| v as u32).collect(),
+            offsets: offsets.into_iter().map(|v| v as u32).collect(),
+        })
+    }
+
+    /// Drops `Image` without cleaning up any resources.
+    ///
+    /// Returns `EGLImage` handle.
+    ///
+    /// Alias for `Into<egl::EGLImage>`.
+    pub fn into_raw(mut self) -> egl::EGLImage {
+        self.terminated = true;
+        self.handle
+    }
+}
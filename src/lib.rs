@@ -7,31 +7,66 @@
 
 /*!
 # EGLI - Higher-level EGL Interface
+
+## Tracing
+
+Set the `EGLI_TRACE` environment variable to `1` to log the creation and
+destruction of `Display`, `Surface` and `Context` handles to stderr, for
+tracking down leaked EGL objects.
 */
 
 extern crate libc;
 #[macro_use]
 extern crate bitflags;
+#[cfg(feature = "raw-window-handle")]
+extern crate raw_window_handle;
 
 pub mod egl;
 pub mod ffi;
 pub mod error;
 
+mod attrib_list;
 mod display;
 mod context;
+mod context_builder;
+mod pbuffer_builder;
 mod window_surface;
 mod config_filter;
 mod frame_buffer_config;
 mod version;
+mod sync;
+mod trace;
+mod device;
+#[cfg(egl_has_1_5)]
+mod image;
+#[cfg(egl_has_1_5)]
+mod platform_display_builder;
+#[cfg(feature = "egl_stream")]
+mod stream;
 
-pub use display::{Display, ContextClientVersion};
+pub use attrib_list::AttribList;
+pub use display::{Display, ContextClientVersion, OwnedCurrentGuard, PartialUpdateSupport};
 pub use context::Context;
-pub use window_surface::Surface;
+pub use context_builder::ContextBuilder;
+pub use pbuffer_builder::PbufferBuilder;
+pub use window_surface::{Surface, SurfaceKind};
+#[cfg(android)]
+pub use window_surface::FrameTimestamps;
 pub use config_filter::ConfigFilterRef;
 pub use frame_buffer_config::FrameBufferConfigRef;
-pub use version::Version;
+pub use version::{Version, VersionParseError};
+pub use sync::Sync;
+pub use device::EglDevice;
+#[cfg(egl_has_1_5)]
+pub use image::Image;
+#[cfg(all(unix, egl_has_1_5, feature = "egl_dmabuf_export"))]
+pub use image::DmabufExport;
+#[cfg(egl_has_1_5)]
+pub use platform_display_builder::PlatformDisplayBuilder;
+#[cfg(feature = "egl_stream")]
+pub use stream::Stream;
 
-use std::mem;
+use std::convert::TryFrom;
 
 /// `[EGL 1.5]` Get supported EGL client version.
 ///
@@ -42,9 +77,9 @@ use std::mem;
 ///
 /// Both the major and minor portions of the version number are numeric.
 /// Their values must match the major and minor values returned by `Display::initialize`.
-#[cfg(feature = "egl_1_5")]
+#[cfg(egl_has_1_5)]
 pub fn query_version() -> error::Result<&'static str> {
-    let cstr = egl::query_string(egl::EGL_NO_DISPLAY, egl::EGL_VERSION)?;
+    let cstr = egl::query_string(egl::EGL_NO_DISPLAY, egl::query_string::EGL_VERSION)?;
     Ok(cstr.to_str()?)
 }
 
@@ -52,25 +87,60 @@ pub fn query_version() -> error::Result<&'static str> {
 ///
 /// Returns a space separated list of supported extensions.
 pub fn query_extensions() -> error::Result<&'static str> {
-    let cstr = egl::query_string(egl::EGL_NO_DISPLAY, egl::EGL_EXTENSIONS)?;
+    let cstr = egl::query_string(egl::EGL_NO_DISPLAY, egl::query_string::EGL_EXTENSIONS)?;
     Ok(cstr.to_str()?)
 }
 
+/// `[EGL 1.0]` Get all supported client extensions as a list.
+///
+/// Same as `query_extensions`, split on whitespace.
+pub fn query_client_extensions_list() -> error::Result<Vec<&'static str>> {
+    Ok(query_extensions()?.split_whitespace().collect())
+}
+
+/// `[EGL 1.0]` Check if the named client extension is supported, such as
+/// `EGL_EXT_platform_base`.
+///
+/// Client extensions do not depend on a display, so this can be used before a
+/// display is created. Returns `false` if the implementation does not support
+/// client extensions at all (`EGL_EXT_client_extensions`), in which case querying
+/// them fails.
+pub fn has_client_extension(name: &str) -> error::Result<bool> {
+    match query_extensions() {
+        Ok(extensions) => Ok(extensions.split_whitespace().any(|e| e == name)),
+        Err(error::Error::Egl(_)) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
 #[repr(i32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ColorBufferType {
     Rgb = 0x308E,
     Luminance = 0x308F,
 }
 
 impl ColorBufferType {
+    #[deprecated(note = "use `ColorBufferType::try_from`")]
     pub unsafe fn from_raw(value: egl::EGLint) -> ColorBufferType {
-        mem::transmute(value as i32)
+        ColorBufferType::try_from(value).expect("unknown EGL_COLOR_BUFFER_TYPE value")
+    }
+}
+
+impl TryFrom<egl::EGLint> for ColorBufferType {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<ColorBufferType> {
+        match value {
+            egl::config_attrib::EGL_RGB_BUFFER => Ok(ColorBufferType::Rgb),
+            egl::config_attrib::EGL_LUMINANCE_BUFFER => Ok(ColorBufferType::Luminance),
+            other => Err(error::Error::UnknownValue(other)),
+        }
     }
 }
 
 #[repr(i32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ConfigCaveat {
     None = 0x3038,
     Slow = 0x3050,
@@ -78,24 +148,218 @@ pub enum ConfigCaveat {
 }
 
 impl ConfigCaveat {
+    #[deprecated(note = "use `ConfigCaveat::try_from`")]
     pub unsafe fn from_raw(value: egl::EGLint) -> ConfigCaveat {
-        mem::transmute(value as i32)
+        ConfigCaveat::try_from(value).expect("unknown EGL_CONFIG_CAVEAT value")
+    }
+}
+
+impl TryFrom<egl::EGLint> for ConfigCaveat {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<ConfigCaveat> {
+        match value {
+            egl::config_attrib::EGL_NONE => Ok(ConfigCaveat::None),
+            egl::config_attrib::EGL_SLOW_CONFIG => Ok(ConfigCaveat::Slow),
+            egl::config_attrib::EGL_NON_CONFORMANT_CONFIG => Ok(ConfigCaveat::NonConformant),
+            other => Err(error::Error::UnknownValue(other)),
+        }
     }
 }
 
 #[repr(i32)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TransparentType {
     None = 0x3038,
     TransparentRgb = 0x3052,
 }
 
 impl TransparentType {
+    #[deprecated(note = "use `TransparentType::try_from`")]
     pub unsafe fn from_raw(value: egl::EGLint) -> TransparentType {
-        mem::transmute(value as i32)
+        TransparentType::try_from(value).expect("unknown EGL_TRANSPARENT_TYPE value")
+    }
+}
+
+impl TryFrom<egl::EGLint> for TransparentType {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<TransparentType> {
+        match value {
+            egl::config_attrib::EGL_NONE => Ok(TransparentType::None),
+            egl::config_attrib::EGL_TRANSPARENT_RGB => Ok(TransparentType::TransparentRgb),
+            other => Err(error::Error::UnknownValue(other)),
+        }
+    }
+}
+
+/// `[EGL 1.2]` Client rendering API, as used by `eglBindAPI` and `eglQueryAPI`.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Api {
+    OpenGlEs = 0x30A0,
+    OpenVg = 0x30A1,
+    OpenGl = 0x30A2,
+}
+
+impl TryFrom<egl::EGLenum> for Api {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLenum) -> error::Result<Api> {
+        match value {
+            egl::api::EGL_OPENGL_ES_API => Ok(Api::OpenGlEs),
+            egl::api::EGL_OPENVG_API => Ok(Api::OpenVg),
+            egl::api::EGL_OPENGL_API => Ok(Api::OpenGl),
+            other => Err(error::Error::UnknownValue(other as i32)),
+        }
+    }
+}
+
+/// `[EGL 1.2]` Buffer which client API rendering is requested to go to.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RenderBuffer {
+    Back = 0x3084,
+    Single = 0x3085,
+}
+
+impl TryFrom<egl::EGLint> for RenderBuffer {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<RenderBuffer> {
+        match value {
+            egl::surface_attrib::EGL_BACK_BUFFER => Ok(RenderBuffer::Back),
+            egl::surface_attrib::EGL_SINGLE_BUFFER => Ok(RenderBuffer::Single),
+            other => Err(error::Error::UnknownValue(other)),
+        }
+    }
+}
+
+/// `[EGL 1.2]` Effect on the color buffer when posting a surface with `eglSwapBuffers`.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SwapBehavior {
+    Preserved = 0x3094,
+    Destroyed = 0x3095,
+}
+
+impl TryFrom<egl::EGLint> for SwapBehavior {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<SwapBehavior> {
+        match value {
+            egl::surface_attrib::EGL_BUFFER_PRESERVED => Ok(SwapBehavior::Preserved),
+            egl::surface_attrib::EGL_BUFFER_DESTROYED => Ok(SwapBehavior::Destroyed),
+            other => Err(error::Error::UnknownValue(other)),
+        }
+    }
+}
+
+/// `[EGL_IMG_context_priority]` Scheduling priority of a rendering context.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ContextPriority {
+    High = 0x3101,
+    Medium = 0x3102,
+    Low = 0x3103,
+}
+
+impl TryFrom<egl::EGLint> for ContextPriority {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<ContextPriority> {
+        match value {
+            egl::context_attrib::EGL_CONTEXT_PRIORITY_HIGH_IMG => Ok(ContextPriority::High),
+            egl::context_attrib::EGL_CONTEXT_PRIORITY_MEDIUM_IMG => Ok(ContextPriority::Medium),
+            egl::context_attrib::EGL_CONTEXT_PRIORITY_LOW_IMG => Ok(ContextPriority::Low),
+            other => Err(error::Error::UnknownValue(other)),
+        }
+    }
+}
+
+/// `[EGL_KHR_stream]` Connection and frame availability state of a `Stream`.
+#[cfg(feature = "egl_stream")]
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StreamState {
+    /// The stream is created, but no consumer is connected yet.
+    Created = 0x3215,
+    /// A consumer is connected, but no producer yet.
+    Connecting = 0x3216,
+    /// Both are connected, but the producer has not inserted any frame yet.
+    Empty = 0x3217,
+    /// The producer inserted a frame the consumer has not used yet.
+    NewFrameAvailable = 0x3218,
+    /// The consumer already used the latest frame.
+    OldFrameAvailable = 0x3219,
+    /// The producer or consumer was destroyed, and the stream can not be used anymore.
+    Disconnected = 0x321A,
+}
+
+#[cfg(feature = "egl_stream")]
+impl TryFrom<egl::EGLint> for StreamState {
+    type Error = error::Error;
+
+    fn try_from(value: egl::EGLint) -> error::Result<StreamState> {
+        use egl::stream;
+
+        match value {
+            stream::EGL_STREAM_STATE_CREATED_KHR => Ok(StreamState::Created),
+            stream::EGL_STREAM_STATE_CONNECTING_KHR => Ok(StreamState::Connecting),
+            stream::EGL_STREAM_STATE_EMPTY_KHR => Ok(StreamState::Empty),
+            stream::EGL_STREAM_STATE_NEW_FRAME_AVAILABLE_KHR => Ok(StreamState::NewFrameAvailable),
+            stream::EGL_STREAM_STATE_OLD_FRAME_AVAILABLE_KHR => Ok(StreamState::OldFrameAvailable),
+            stream::EGL_STREAM_STATE_DISCONNECTED_KHR => Ok(StreamState::Disconnected),
+            other => Err(error::Error::UnknownValue(other)),
+        }
     }
 }
 
+#[cfg(feature = "egl_stream")]
+const _: () = {
+    assert!(StreamState::Created as egl::EGLint == egl::stream::EGL_STREAM_STATE_CREATED_KHR);
+    assert!(StreamState::Connecting as egl::EGLint == egl::stream::EGL_STREAM_STATE_CONNECTING_KHR);
+    assert!(StreamState::Empty as egl::EGLint == egl::stream::EGL_STREAM_STATE_EMPTY_KHR);
+    assert!(StreamState::NewFrameAvailable as egl::EGLint ==
+            egl::stream::EGL_STREAM_STATE_NEW_FRAME_AVAILABLE_KHR);
+    assert!(StreamState::OldFrameAvailable as egl::EGLint ==
+            egl::stream::EGL_STREAM_STATE_OLD_FRAME_AVAILABLE_KHR);
+    assert!(StreamState::Disconnected as egl::EGLint ==
+            egl::stream::EGL_STREAM_STATE_DISCONNECTED_KHR);
+};
+
+// The enum discriminants must match the EGL constants they stand for.
+const _: () = {
+    assert!(ColorBufferType::Rgb as egl::EGLint == egl::config_attrib::EGL_RGB_BUFFER);
+    assert!(ColorBufferType::Luminance as egl::EGLint == egl::config_attrib::EGL_LUMINANCE_BUFFER);
+
+    assert!(ConfigCaveat::None as egl::EGLint == egl::config_attrib::EGL_NONE);
+    assert!(ConfigCaveat::Slow as egl::EGLint == egl::config_attrib::EGL_SLOW_CONFIG);
+    assert!(ConfigCaveat::NonConformant as egl::EGLint ==
+            egl::config_attrib::EGL_NON_CONFORMANT_CONFIG);
+
+    assert!(TransparentType::None as egl::EGLint == egl::config_attrib::EGL_NONE);
+    assert!(TransparentType::TransparentRgb as egl::EGLint ==
+            egl::config_attrib::EGL_TRANSPARENT_RGB);
+
+    assert!(Api::OpenGlEs as egl::EGLenum == egl::api::EGL_OPENGL_ES_API);
+    assert!(Api::OpenVg as egl::EGLenum == egl::api::EGL_OPENVG_API);
+    assert!(Api::OpenGl as egl::EGLenum == egl::api::EGL_OPENGL_API);
+
+    assert!(RenderBuffer::Back as egl::EGLint == egl::surface_attrib::EGL_BACK_BUFFER);
+    assert!(RenderBuffer::Single as egl::EGLint == egl::surface_attrib::EGL_SINGLE_BUFFER);
+
+    assert!(SwapBehavior::Preserved as egl::EGLint == egl::surface_attrib::EGL_BUFFER_PRESERVED);
+    assert!(SwapBehavior::Destroyed as egl::EGLint == egl::surface_attrib::EGL_BUFFER_DESTROYED);
+
+    assert!(ContextPriority::High as egl::EGLint ==
+            egl::context_attrib::EGL_CONTEXT_PRIORITY_HIGH_IMG);
+    assert!(ContextPriority::Medium as egl::EGLint ==
+            egl::context_attrib::EGL_CONTEXT_PRIORITY_MEDIUM_IMG);
+    assert!(ContextPriority::Low as egl::EGLint ==
+            egl::context_attrib::EGL_CONTEXT_PRIORITY_LOW_IMG);
+};
+
 bitflags! {
     /// Renderable type mask bits.
     pub struct RenderableType: i32 {
@@ -112,6 +376,42 @@ bitflags! {
     }
 }
 
+impl Default for RenderableType {
+    /// Returns `RenderableType::OPENGL_ES`, the EGL default for `EGL_RENDERABLE_TYPE`.
+    fn default() -> RenderableType {
+        RenderableType::OPENGL_ES
+    }
+}
+
+impl RenderableType {
+    const NAMES: &'static [(&'static str, RenderableType)] = &[
+        ("OPENGL", RenderableType::OPENGL),
+        ("OPENGL_ES", RenderableType::OPENGL_ES),
+        ("OPENGL_ES2", RenderableType::OPENGL_ES2),
+        ("OPENGL_ES3", RenderableType::OPENGL_ES3),
+        ("OPENVG", RenderableType::OPENVG),
+    ];
+
+    /// Returns the names of all set flags, in declaration order.
+    ///
+    /// Bits that do not correspond to a known flag are not included.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::RenderableType;
+    ///
+    /// let renderable = RenderableType::OPENGL_ES2 | RenderableType::OPENGL_ES3;
+    /// assert_eq!(renderable.set_flag_names(), vec!["OPENGL_ES2", "OPENGL_ES3"]);
+    /// ```
+    pub fn set_flag_names(&self) -> Vec<&'static str> {
+        RenderableType::NAMES.iter()
+                             .filter(|&&(_, flag)| self.contains(flag))
+                             .map(|&(name, _)| name)
+                             .collect()
+    }
+}
+
 bitflags! {
     /// Surface type mask bits.
     pub struct SurfaceType: i32 {
@@ -131,3 +431,41 @@ bitflags! {
         const SWAP_BEHAVIOR_PRESERVED  = 0x0400;
     }
 }
+
+impl Default for SurfaceType {
+    /// Returns `SurfaceType::WINDOW`, the EGL default for `EGL_SURFACE_TYPE`.
+    fn default() -> SurfaceType {
+        SurfaceType::WINDOW
+    }
+}
+
+impl SurfaceType {
+    const NAMES: &'static [(&'static str, SurfaceType)] = &[
+        ("PBUFFER", SurfaceType::PBUFFER),
+        ("PIXMAP", SurfaceType::PIXMAP),
+        ("WINDOW", SurfaceType::WINDOW),
+        ("VG_COLORSPACE_LINEAR", SurfaceType::VG_COLORSPACE_LINEAR),
+        ("VG_ALPHA_FORMAT_PRE", SurfaceType::VG_ALPHA_FORMAT_PRE),
+        ("MULTISAMPLE_RESOLVE_BOX", SurfaceType::MULTISAMPLE_RESOLVE_BOX),
+        ("SWAP_BEHAVIOR_PRESERVED", SurfaceType::SWAP_BEHAVIOR_PRESERVED),
+    ];
+
+    /// Returns the names of all set flags, in declaration order.
+    ///
+    /// Bits that do not correspond to a known flag are not included.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::SurfaceType;
+    ///
+    /// let surface = SurfaceType::WINDOW | SurfaceType::PBUFFER;
+    /// assert_eq!(surface.set_flag_names(), vec!["PBUFFER", "WINDOW"]);
+    /// ```
+    pub fn set_flag_names(&self) -> Vec<&'static str> {
+        SurfaceType::NAMES.iter()
+                          .filter(|&&(_, flag)| self.contains(flag))
+                          .map(|&(name, _)| name)
+                          .collect()
+    }
+}
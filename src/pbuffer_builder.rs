@@ -0,0 +1,112 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::rc::Rc;
+use egl::{self, EGLDisplay, EGLint};
+use error::{Error, Result};
+use {FrameBufferConfigRef, Surface, SurfaceKind, SurfaceType};
+use config_filter::unsigned_attrib;
+
+/// `[EGL 1.0]` Pixel buffer surface builder.
+///
+/// Created with `Display::pbuffer_builder`. Collects the attributes passed to
+/// `eglCreatePbufferSurface`; attributes that are not set are not sent to EGL.
+#[must_use = "the builder does nothing unless `create` is called"]
+pub struct PbufferBuilder {
+    handle: EGLDisplay,
+    display_token: Rc<()>,
+    initialized: bool,
+    config: FrameBufferConfigRef,
+    width: Option<[EGLint; 2]>,
+    height: Option<[EGLint; 2]>,
+    largest: Option<[EGLint; 2]>,
+}
+
+impl PbufferBuilder {
+    pub(crate) fn new(handle: EGLDisplay,
+                      display_token: Rc<()>,
+                      initialized: bool,
+                      config: FrameBufferConfigRef)
+                      -> PbufferBuilder {
+        PbufferBuilder {
+            handle,
+            display_token,
+            initialized,
+            config,
+            width: None,
+            height: None,
+            largest: None,
+        }
+    }
+
+    /// Requested width of the pbuffer, in pixels. The default value is 0.
+    ///
+    /// Sets `EGL_WIDTH` attribute.
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = Some([egl::surface_attrib::EGL_WIDTH, unsigned_attrib(width)]);
+        self
+    }
+
+    /// Requested height of the pbuffer, in pixels. The default value is 0.
+    ///
+    /// Sets `EGL_HEIGHT` attribute.
+    pub fn with_height(mut self, height: u32) -> Self {
+        self.height = Some([egl::surface_attrib::EGL_HEIGHT, unsigned_attrib(height)]);
+        self
+    }
+
+    /// If `true`, and the requested size can not be allocated, the largest available
+    /// pbuffer is allocated instead of failing. Its size may be smaller than requested
+    /// in either dimension. The default value is `false`.
+    ///
+    /// Sets `EGL_LARGEST_PBUFFER` attribute.
+    pub fn with_largest(mut self, largest: bool) -> Self {
+        self.largest = Some([egl::surface_attrib::EGL_LARGEST_PBUFFER,
+                             if largest {
+                                 egl::EGL_TRUE as EGLint
+                             } else {
+                                 egl::EGL_FALSE as EGLint
+                             }]);
+        self
+    }
+
+    /// Create the pbuffer with `eglCreatePbufferSurface`.
+    ///
+    /// Returns the surface with its actual width and height, queried with
+    /// `eglQuerySurface`. When `with_largest(true)` is set, the size may be smaller
+    /// than requested, and the returned size must be used instead.
+    ///
+    /// Fails with `Error::ConfigNotPbufferCapable` if the config's surface type does
+    /// not include `SurfaceType::PBUFFER`.
+    pub fn create(self) -> Result<(Surface, u32, u32)> {
+        if !self.initialized {
+            return Err(Error::DisplayNotInitialized);
+        }
+        if !self.config.surface_type()?.contains(SurfaceType::PBUFFER) {
+            return Err(Error::ConfigNotPbufferCapable);
+        }
+
+        let attrib_list: Vec<_> = [self.width, self.height, self.largest]
+                                      .iter()
+                                      .flatten()
+                                      .flatten()
+                                      .chain(&[egl::config_attrib::EGL_NONE])
+                                      .cloned()
+                                      .collect();
+
+        let handle = egl::create_pbuffer_surface(self.handle, self.config.handle(), &attrib_list)
+                         .map_err(Error::from_failed_call)?;
+        let surface = Surface::from_raw(self.handle, handle)
+                          .with_kind(SurfaceKind::Pbuffer)
+                          .with_display_token(self.display_token);
+
+        let width = surface.query_width()?;
+        let height = surface.query_height()?;
+
+        Ok((surface, width as u32, height as u32))
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use libc::c_void;
+use egl::{self, EGLAttrib, EGLenum, EGLint};
+use error::Result;
+use Display;
+
+/// `[EGL 1.5]` Platform display builder.
+///
+/// Collects the `EGLAttrib` attributes passed to `eglGetPlatformDisplay`, and
+/// terminates the list with `EGL_NONE`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::ptr;
+/// use egli::{egl, PlatformDisplayBuilder};
+///
+/// let display = PlatformDisplayBuilder::new()
+///                   .attrib(egl::platform::EGL_PLATFORM_X11_SCREEN_EXT, 0)
+///                   .build(egl::platform::EGL_PLATFORM_X11_EXT, ptr::null_mut())
+///                   .expect("failed to get platform display");
+/// ```
+#[derive(Clone, Debug, Default)]
+#[must_use = "the builder does nothing unless `build` is called"]
+pub struct PlatformDisplayBuilder {
+    attribs: Vec<EGLAttrib>,
+}
+
+impl PlatformDisplayBuilder {
+    /// Create a builder with an empty attribute list.
+    pub fn new() -> PlatformDisplayBuilder {
+        PlatformDisplayBuilder { attribs: Vec::new() }
+    }
+
+    /// Add an attribute, such as `egl::platform::EGL_PLATFORM_X11_SCREEN_EXT`, with its value.
+    pub fn attrib(mut self, key: EGLint, value: EGLAttrib) -> Self {
+        self.attribs.push(key as EGLAttrib);
+        self.attribs.push(value);
+        self
+    }
+
+    /// Get the display for `native_display` of the given `platform` with
+    /// `eglGetPlatformDisplay`.
+    ///
+    /// `native_display` is a pointer to the platform's native display, for example
+    /// an X11 `Display*`, or null for the platform's default display.
+    pub fn build(&self, platform: EGLenum, native_display: *mut c_void) -> Result<Display> {
+        let attrib_list: Vec<_> = self.attribs
+                                      .iter()
+                                      .cloned()
+                                      .chain(Some(egl::config_attrib::EGL_NONE as EGLAttrib))
+                                      .collect();
+
+        Display::from_platform_display(platform, native_display, &attrib_list)
+    }
+}
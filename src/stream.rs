@@ -0,0 +1,134 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::convert::TryFrom;
+use std::rc::Rc;
+use egl;
+use error::Result;
+use StreamState;
+
+/// `[EGL_KHR_stream]` [RAII](https://en.wikipedia.org/wiki/Resource_Acquisition_Is_Initialization) wrapper for
+/// EGLStreamKHR.
+///
+/// When dropped, frees up the stream with `eglDestroyStreamKHR` call.
+///
+/// A stream passes frames from a producer, such as an EGL surface or a camera, to a
+/// consumer, such as a client API texture. Producers and consumers are connected with
+/// functions of other extensions, using the raw handle.
+///
+/// Only available with the `egl_stream` feature.
+#[must_use = "dropping a `Stream` destroys it immediately"]
+pub struct Stream {
+    terminated: bool,
+    display_handle: egl::EGLDisplay,
+    handle: egl::EGLStreamKHR,
+    display_token: Option<Rc<()>>,
+}
+
+impl Drop for Stream {
+    fn drop(&mut self) {
+        if !self.terminated {
+            let _ = egl::destroy_stream_khr(self.display_handle, self.handle);
+        }
+    }
+}
+
+impl Into<egl::EGLStreamKHR> for Stream {
+    fn into(self) -> egl::EGLStreamKHR {
+        self.into_raw()
+    }
+}
+
+impl Stream {
+    /// Create a `Stream` from an existing EGL display and stream handles.
+    ///
+    /// The created `Stream` takes ownership of the handle and destroys it when dropped.
+    pub fn from_raw(display_handle: egl::EGLDisplay, stream_handle: egl::EGLStreamKHR) -> Stream {
+        Stream {
+            terminated: false,
+            display_handle,
+            handle: stream_handle,
+            display_token: None,
+        }
+    }
+
+    /// Keeps a token of the `Display` that created this object, so the display
+    /// can detect that it is dropped too early.
+    pub(crate) fn with_display_token(mut self, token: Rc<()>) -> Stream {
+        self.display_token = Some(token);
+        self
+    }
+
+    /// Get raw handle.
+    pub fn handle(&self) -> egl::EGLStreamKHR {
+        self.handle
+    }
+
+    /// Get raw handle of the display this stream was created on.
+    pub fn display_handle(&self) -> egl::EGLDisplay {
+        self.display_handle
+    }
+
+    /// Returns the connection and frame availability state of the stream.
+    ///
+    /// Result of `eglQueryStreamKHR` with `EGL_STREAM_STATE_KHR` parameter.
+    pub fn state(&self) -> Result<StreamState> {
+        let value = egl::query_stream_khr(self.display_handle,
+                                          self.handle,
+                                          egl::stream::EGL_STREAM_STATE_KHR)?;
+        StreamState::try_from(value)
+    }
+
+    /// Returns the number of frames inserted into the stream by the producer.
+    ///
+    /// Result of `eglQueryStreamu64KHR` with `EGL_PRODUCER_FRAME_KHR` parameter.
+    pub fn producer_frame(&self) -> Result<u64> {
+        egl::query_stream_u64_khr(self.display_handle,
+                                  self.handle,
+                                  egl::stream::EGL_PRODUCER_FRAME_KHR)
+    }
+
+    /// Returns the number of the frame the consumer is currently using.
+    ///
+    /// Result of `eglQueryStreamu64KHR` with `EGL_CONSUMER_FRAME_KHR` parameter.
+    pub fn consumer_frame(&self) -> Result<u64> {
+        egl::query_stream_u64_khr(self.display_handle,
+                                  self.handle,
+                                  egl::stream::EGL_CONSUMER_FRAME_KHR)
+    }
+
+    /// Returns the expected time between the producer inserting a frame and the consumer
+    /// showing it, in microseconds.
+    ///
+    /// Result of `eglQueryStreamKHR` with `EGL_CONSUMER_LATENCY_USEC_KHR` parameter.
+    pub fn consumer_latency(&self) -> Result<i32> {
+        egl::query_stream_khr(self.display_handle,
+                              self.handle,
+                              egl::stream::EGL_CONSUMER_LATENCY_USEC_KHR)
+    }
+
+    /// Sets the expected time between the producer inserting a frame and the consumer
+    /// showing it, in microseconds, so the producer can time its frames.
+    ///
+    /// Calls `eglStreamAttribKHR` with `EGL_CONSUMER_LATENCY_USEC_KHR` attribute.
+    pub fn set_consumer_latency(&self, usec: i32) -> Result<()> {
+        egl::stream_attrib_khr(self.display_handle,
+                               self.handle,
+                               egl::stream::EGL_CONSUMER_LATENCY_USEC_KHR,
+                               usec)
+    }
+
+    /// Drops `Stream` without cleaning up any resources.
+    ///
+    /// Returns `EGLStreamKHR` handle.
+    ///
+    /// Alias for `Into<egl::EGLStreamKHR>`.
+    pub fn into_raw(mut self) -> egl::EGLStreamKHR {
+        self.terminated = true;
+        self.handle
+    }
+}
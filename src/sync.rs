@@ -0,0 +1,175 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::rc::Rc;
+use std::time::Duration;
+#[cfg(all(android, egl_has_1_5))]
+use std::os::unix::io::RawFd;
+use egl;
+use error::Result;
+
+/// `[EGL 1.5]` or `[EGL_KHR_fence_sync]` [RAII](https://en.wikipedia.org/wiki/Resource_Acquisition_Is_Initialization)
+/// wrapper for EGLSync.
+///
+/// The sync object is either created with the EGL 1.5 core functions, or with the
+/// `EGL_KHR_fence_sync` extension functions on older implementations. It is always
+/// used with the same set of functions it was created with.
+///
+/// When dropped, frees up the sync object with `eglDestroySync` (or `eglDestroySyncKHR`) call.
+#[must_use = "dropping a `Sync` destroys it immediately"]
+pub struct Sync {
+    terminated: bool,
+    display_handle: egl::EGLDisplay,
+    handle: egl::EGLSyncKHR,
+    api: SyncApi,
+    display_token: Option<Rc<()>>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SyncApi {
+    #[cfg(egl_has_1_5)]
+    Core,
+    Khr,
+}
+
+impl Drop for Sync {
+    fn drop(&mut self) {
+        if !self.terminated {
+            match self.api {
+                #[cfg(egl_has_1_5)]
+                SyncApi::Core => {
+                    let _ = egl::destroy_sync(self.display_handle, self.handle);
+                }
+                SyncApi::Khr => {
+                    let _ = egl::destroy_sync_khr(self.display_handle, self.handle);
+                }
+            }
+        }
+    }
+}
+
+impl Into<egl::EGLSyncKHR> for Sync {
+    fn into(self) -> egl::EGLSyncKHR {
+        self.into_raw()
+    }
+}
+
+impl Sync {
+    /// Create a `Sync` from an existing EGL display and sync handles, where the
+    /// sync object was created with `eglCreateSync`.
+    ///
+    /// The created `Sync` takes ownership of the handle and destroys it when dropped.
+    #[cfg(egl_has_1_5)]
+    pub fn from_raw(display_handle: egl::EGLDisplay, sync_handle: egl::EGLSync) -> Sync {
+        Sync {
+            terminated: false,
+            display_handle,
+            handle: sync_handle,
+            api: SyncApi::Core,
+            display_token: None,
+        }
+    }
+
+    /// Create a `Sync` from an existing EGL display and sync handles, where the
+    /// sync object was created with `eglCreateSyncKHR`.
+    ///
+    /// The created `Sync` takes ownership of the handle and destroys it when dropped.
+    pub fn from_raw_khr(display_handle: egl::EGLDisplay, sync_handle: egl::EGLSyncKHR) -> Sync {
+        Sync {
+            terminated: false,
+            display_handle,
+            handle: sync_handle,
+            api: SyncApi::Khr,
+            display_token: None,
+        }
+    }
+
+    /// Keeps a token of the `Display` that created this object, so the display
+    /// can detect that it is dropped too early.
+    pub(crate) fn with_display_token(mut self, token: Rc<()>) -> Sync {
+        self.display_token = Some(token);
+        self
+    }
+
+    /// Get raw handle.
+    pub fn handle(&self) -> egl::EGLSyncKHR {
+        self.handle
+    }
+
+    /// `[EGL 1.5]` or `[EGL_KHR_fence_sync]` Block the calling thread until the sync object is signalled,
+    /// or until `timeout` passes. If `timeout` is `None`, waits forever.
+    ///
+    /// Pending client API commands are flushed before waiting, so that waiting on a
+    /// fence that was not yet submitted can not block forever.
+    ///
+    /// Returns `true` if the sync object was signalled, `false` if the timeout
+    /// expired first.
+    pub fn wait_for_completion(&self, timeout: Option<Duration>) -> Result<bool> {
+        let timeout = match timeout {
+            Some(duration) => {
+                duration.as_secs()
+                        .saturating_mul(1_000_000_000)
+                        .saturating_add(duration.subsec_nanos() as u64)
+            }
+            None => egl::sync::EGL_FOREVER_KHR,
+        };
+
+        let satisfied = match self.api {
+            #[cfg(egl_has_1_5)]
+            SyncApi::Core => {
+                egl::client_wait_sync(self.display_handle,
+                                      self.handle,
+                                      egl::sync::EGL_SYNC_FLUSH_COMMANDS_BIT,
+                                      timeout)? == egl::sync::EGL_CONDITION_SATISFIED
+            }
+            SyncApi::Khr => {
+                egl::client_wait_sync_khr(self.display_handle,
+                                          self.handle,
+                                          egl::sync::EGL_SYNC_FLUSH_COMMANDS_BIT_KHR,
+                                          timeout)? == egl::sync::EGL_CONDITION_SATISFIED_KHR
+            }
+        };
+
+        Ok(satisfied)
+    }
+
+    /// `[EGL 1.5]` or `[EGL_KHR_wait_sync]` Make the server wait for the sync object
+    /// to be signalled before executing further client API commands of the current
+    /// context. Does not block the calling thread.
+    ///
+    /// For a sync object created with the extension functions, this requires the
+    /// `EGL_KHR_wait_sync` extension.
+    pub fn wait_on_server(&self) -> Result<()> {
+        match self.api {
+            #[cfg(egl_has_1_5)]
+            SyncApi::Core => egl::wait_sync(self.display_handle, self.handle, 0)?,
+            SyncApi::Khr => egl::wait_sync_khr(self.display_handle, self.handle, 0)?,
+        }
+        Ok(())
+    }
+
+    /// `[EGL_ANDROID_native_fence_sync]` Export this native fence sync object as a
+    /// native fence file descriptor, for example to hand GPU work to SurfaceFlinger.
+    ///
+    /// The sync object must be created with `Display::insert_native_fence`, and the
+    /// client API commands must be flushed. The returned file descriptor is owned by
+    /// the caller, who is responsible for closing it.
+    #[cfg(all(android, egl_has_1_5))]
+    pub fn dup_native_fence_fd(&self) -> Result<RawFd> {
+        Ok(egl::dup_native_fence_fd_android(self.display_handle, self.handle)?)
+    }
+
+    /// Drops `Sync` without cleaning up any resources.
+    ///
+    /// Returns `EGLSync` (or `EGLSyncKHR`) handle.
+    ///
+    /// Alias for `Into<egl::EGLSyncKHR>`.
+    pub fn into_raw(mut self) -> egl::EGLSyncKHR {
+        self.terminated = true;
+        self.handle
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2016 The EGLI Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::env;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use libc::c_void;
+
+const UNKNOWN: usize = 0;
+const DISABLED: usize = 1;
+const ENABLED: usize = 2;
+
+static STATE: AtomicUsize = AtomicUsize::new(UNKNOWN);
+
+/// Returns `true` if the `EGLI_TRACE` environment variable is set to `1`.
+///
+/// The variable is read once; later calls only load the cached result.
+#[inline]
+pub(crate) fn enabled() -> bool {
+    match STATE.load(Ordering::Relaxed) {
+        UNKNOWN => {
+            let enabled = env::var_os("EGLI_TRACE").is_some_and(|value| value == "1");
+            STATE.store(if enabled { ENABLED } else { DISABLED }, Ordering::Relaxed);
+            enabled
+        }
+        state => state == ENABLED,
+    }
+}
+
+/// Logs an `event`, such as creation or destruction, of an EGL object to stderr
+/// if tracing is enabled.
+#[inline]
+pub(crate) fn trace(object: &str, event: &str, handle: *mut c_void) {
+    if enabled() {
+        eprintln!("egli: {} {} {:p}", object, event, handle);
+    }
+}
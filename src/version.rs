@@ -5,17 +5,107 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use std::error;
 use std::fmt;
+use std::str::FromStr;
 
 /// `[EGL 1.0]` EGL version.
-#[derive(Copy, Clone, Debug)]
+///
+/// Can be parsed from an `EGL_VERSION` string, which is laid out as
+/// `major_version.minor_version space vendor_specific_info`. The vendor specific
+/// part is ignored.
+///
+/// ## Example
+///
+/// ```
+/// use egli::Version;
+///
+/// let version: Version = "1.4 Mesa 20.0.8".parse().unwrap();
+/// assert_eq!((version.major, version.minor), (1, 4));
+///
+/// let version: Version = "1.5".parse().unwrap();
+/// assert_eq!((version.major, version.minor), (1, 5));
+///
+/// assert!("".parse::<Version>().is_err());
+/// assert!("1".parse::<Version>().is_err());
+/// assert!("1.".parse::<Version>().is_err());
+/// assert!("1.5.2".parse::<Version>().is_err());
+/// assert!("one.five".parse::<Version>().is_err());
+/// assert!(" 1.5".parse::<Version>().is_err());
+/// ```
+///
+/// Versions are ordered by major, then minor version, so `Ord::min` and `Ord::max`
+/// can be used to negotiate a version:
+///
+/// ```
+/// use egli::Version;
+///
+/// let app = Version { major: 1, minor: 5 };
+/// let driver = Version { major: 1, minor: 4 };
+/// assert_eq!(app.min(driver), driver);
+/// assert_eq!(app.max(driver), app);
+/// assert!(Version { major: 2, minor: 0 } > app);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Version {
     pub major: i32,
     pub minor: i32,
 }
 
+impl Version {
+    /// Returns this version, lowered to `supported` if it is higher.
+    ///
+    /// Gives the highest version both sides support, when this is the highest
+    /// version an application supports and `supported` is the one reported by the
+    /// driver.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::Version;
+    ///
+    /// let app = Version { major: 1, minor: 5 };
+    /// assert_eq!(app.clamp_to(Version { major: 1, minor: 4 }),
+    ///            Version { major: 1, minor: 4 });
+    /// assert_eq!(app.clamp_to(Version { major: 1, minor: 5 }), app);
+    /// assert_eq!(app.clamp_to(Version { major: 2, minor: 0 }), app);
+    /// ```
+    pub fn clamp_to(self, supported: Version) -> Version {
+        self.min(supported)
+    }
+}
+
 impl fmt::Display for Version {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}.{}", self.major, self.minor)
     }
 }
+
+impl FromStr for Version {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Version, VersionParseError> {
+        let number = s.split(' ').next().unwrap_or("");
+        let mut parts = number.split('.');
+
+        let major = parts.next().and_then(|p| p.parse().ok()).ok_or(VersionParseError(()))?;
+        let minor = parts.next().and_then(|p| p.parse().ok()).ok_or(VersionParseError(()))?;
+        if parts.next().is_some() {
+            return Err(VersionParseError(()));
+        }
+
+        Ok(Version { major, minor })
+    }
+}
+
+/// Error returned when parsing a `Version` from a string fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VersionParseError(());
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid EGL version, expected `major.minor`")
+    }
+}
+
+impl error::Error for VersionParseError {}
@@ -5,25 +5,73 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::ptr;
+use std::rc::Rc;
 use egl;
-use error::Result;
+use trace;
+use error::{Error, Result};
+use {Display, FrameBufferConfigRef, RenderBuffer, SwapBehavior};
 
 /// `[EGL 1.0]` [RAII](https://en.wikipedia.org/wiki/Resource_Acquisition_Is_Initialization) wrapper for
 /// EGLSurface.
 ///
 /// When dropped, frees up the surface with `eglDestroySurface` call.
 ///
-/// Note that the surface would not be immediately freed if it is current to any thread.
-/// In such a case, the surface will be freed when it is no longer used.
+/// If the surface is the current draw or read surface of the dropping thread, the
+/// current context of the thread is released first, so the surface is freed
+/// immediately. Note that the surface would not be immediately freed if it is current
+/// to another thread. In such a case, the surface will be freed when it is no longer
+/// used.
+#[must_use = "dropping a `Surface` destroys it immediately"]
 pub struct Surface {
     terminated: bool,
     display_handle: egl::EGLDisplay,
     handle: egl::EGLSurface,
+    kind: SurfaceKind,
+    display_token: Option<Rc<()>>,
+    extensions: Rc<Cell<Option<&'static str>>>,
+}
+
+/// `[EGL_ANDROID_get_frame_timestamps]` Timestamps of a swapped frame, in nanoseconds.
+///
+/// A timestamp is `None` if it will never be available, for example if the frame was
+/// not composited.
+#[cfg(android)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FrameTimestamps {
+    pub requested_present_time: Option<i64>,
+    pub rendering_complete_time: Option<i64>,
+    pub composition_latch_time: Option<i64>,
+    pub first_composition_start_time: Option<i64>,
+    pub last_composition_start_time: Option<i64>,
+    pub first_composition_gpu_finished_time: Option<i64>,
+    pub display_present_time: Option<i64>,
+    pub dequeue_ready_time: Option<i64>,
+    pub reads_done_time: Option<i64>,
+}
+
+/// Kind of a `Surface`, recorded by the method that created it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SurfaceKind {
+    Window,
+    Pbuffer,
+    Pixmap,
+    /// The surface was created from a raw handle, and EGL can not report its kind.
+    Unknown,
 }
 
 impl Drop for Surface {
     fn drop(&mut self) {
         if !self.terminated {
+            trace::trace("Surface", "destroyed", self.handle);
+            if self.is_current() {
+                let _ = egl::make_current(self.display_handle,
+                                          egl::EGL_NO_SURFACE,
+                                          egl::EGL_NO_SURFACE,
+                                          egl::EGL_NO_CONTEXT);
+            }
             let _ = egl::destroy_surface(self.display_handle, self.handle);
         }
     }
@@ -31,46 +79,399 @@ impl Drop for Surface {
 
 impl Into<egl::EGLSurface> for Surface {
     fn into(self) -> egl::EGLSurface {
-        self.forget()
+        self.into_raw()
     }
 }
 
 impl Surface {
     /// Create a `Surface` from an existing EGL display and surface handles.
-    pub fn from_handle(display_handle: egl::EGLDisplay,
-                       surface_handle: egl::EGLSurface)
-                       -> Surface {
+    ///
+    /// The created `Surface` takes ownership of the handle and destroys it when dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use egli::Surface;
+    /// use egli::egl::{EGLDisplay, EGLSurface};
+    ///
+    /// let display_handle = 1 as EGLDisplay;
+    /// let surface_handle = 2 as EGLSurface;
+    ///
+    /// let surface = Surface::from_raw(display_handle, surface_handle);
+    /// assert_eq!(surface.into_raw(), surface_handle);
+    /// ```
+    pub fn from_raw(display_handle: egl::EGLDisplay,
+                    surface_handle: egl::EGLSurface)
+                    -> Surface {
+        trace::trace("Surface", "created", surface_handle);
         Surface {
             terminated: false,
             display_handle: display_handle,
             handle: surface_handle,
+            kind: SurfaceKind::Unknown,
+            display_token: None,
+            extensions: Rc::new(Cell::new(None)),
         }
     }
 
+    /// Create a `Surface` from an existing EGL display and surface handles.
+    #[deprecated(note = "use `Surface::from_raw`")]
+    pub fn from_handle(display_handle: egl::EGLDisplay,
+                       surface_handle: egl::EGLSurface)
+                       -> Surface {
+        Surface::from_raw(display_handle, surface_handle)
+    }
+
+    /// Keeps a token of the `Display` that created this object, so the display
+    /// can detect that it is dropped too early.
+    pub(crate) fn with_display_token(mut self, token: Rc<()>) -> Surface {
+        self.display_token = Some(token);
+        self
+    }
+
+    /// Shares the cached extension list of the `Display` that created this object.
+    pub(crate) fn with_display_extensions(mut self,
+                                          extensions: Rc<Cell<Option<&'static str>>>)
+                                          -> Surface {
+        self.extensions = extensions;
+        self
+    }
+
+    pub(crate) fn with_kind(mut self, kind: SurfaceKind) -> Surface {
+        self.kind = kind;
+        self
+    }
+
+    /// Returns whether this is a window, pbuffer or pixmap surface.
+    ///
+    /// A `Surface` created with `from_raw` is `SurfaceKind::Unknown`.
+    pub fn kind(&self) -> SurfaceKind {
+        self.kind
+    }
+
     /// Get raw handle.
     pub fn handle(&self) -> egl::EGLSurface {
         self.handle
     }
 
+    /// Get raw handle of the display this surface was created on.
+    pub fn display_handle(&self) -> egl::EGLDisplay {
+        self.display_handle
+    }
+
+    /// `[EGL 1.0]` Returns the value of a surface attribute.
+    ///
+    /// Escape hatch for attributes without a typed accessor, such as ones added by
+    /// extensions (`EGL_BUFFER_AGE_EXT`, for example). The typed accessors use it too.
+    ///
+    /// Fails with `Error::DisplayNotInitialized` if the display was terminated, and with
+    /// `Error::BadSurface` if the handle is not a valid surface.
+    ///
+    /// Result of `eglQuerySurface` with the `attribute` parameter.
+    pub fn query_attrib(&self, attribute: egl::EGLint) -> Result<i32> {
+        egl::query_surface_value(self.display_handle, self.handle, attribute)
+            .map_err(Error::from_failed_call)
+    }
+
     /// [EGL 1.0] Returns the width of the surface in pixels.
     ///
     /// Result of `eglQuerySurface` with `EGL_WIDTH` parameter.
     pub fn query_width(&self) -> Result<i32> {
-        let mut value: egl::EGLint = 0;
-        egl::query_surface(self.display_handle, self.handle, egl::EGL_WIDTH, &mut value)?;
-        Ok(value as i32)
+        self.query_attrib(egl::surface_attrib::EGL_WIDTH)
     }
 
     /// [EGL 1.0] Returns the height of the surface in pixels.
     ///
     /// Result of `eglQuerySurface` with `EGL_HEIGHT` parameter.
     pub fn query_height(&self) -> Result<i32> {
-        let mut value: egl::EGLint = 0;
-        egl::query_surface(self.display_handle,
-                                self.handle,
-                                egl::EGL_HEIGHT,
-                                &mut value)?;
-        Ok(value as i32)
+        self.query_attrib(egl::surface_attrib::EGL_HEIGHT)
+    }
+
+    /// [EGL 1.2] Returns which buffer client API rendering goes to.
+    ///
+    /// Window surfaces normally render to the back buffer, and their contents are shown
+    /// with `swap_buffers`. Pbuffers always render to the single buffer, so
+    /// `swap_buffers` has no effect on them, and a client API flush (such as `glFlush`)
+    /// is enough to complete rendering.
+    ///
+    /// This is the buffer requested at creation; use `Context::render_buffer` to get
+    /// the buffer a current context actually renders to.
+    ///
+    /// Result of `eglQuerySurface` with `EGL_RENDER_BUFFER` parameter.
+    pub fn render_buffer(&self) -> Result<RenderBuffer> {
+        let value = self.query_attrib(egl::surface_attrib::EGL_RENDER_BUFFER)?;
+        RenderBuffer::try_from(value)
+    }
+
+    /// [EGL 1.2] Returns the horizontal dot pitch of the display on which a window
+    /// surface is visible, in pixels per meter.
+    ///
+    /// Returns `None` if the value is unknown.
+    ///
+    /// Result of `eglQuerySurface` with `EGL_HORIZONTAL_RESOLUTION` parameter.
+    pub fn horizontal_resolution(&self) -> Result<Option<f64>> {
+        self.query_scaled(egl::surface_attrib::EGL_HORIZONTAL_RESOLUTION)
+    }
+
+    /// [EGL 1.2] Returns the vertical dot pitch of the display on which a window
+    /// surface is visible, in pixels per meter.
+    ///
+    /// Returns `None` if the value is unknown.
+    ///
+    /// Result of `eglQuerySurface` with `EGL_VERTICAL_RESOLUTION` parameter.
+    pub fn vertical_resolution(&self) -> Result<Option<f64>> {
+        self.query_scaled(egl::surface_attrib::EGL_VERTICAL_RESOLUTION)
+    }
+
+    /// [EGL 1.2] Returns the ratio of the physical width to height of a pixel on the
+    /// display on which a window surface is visible.
+    ///
+    /// Returns `None` if the value is unknown.
+    ///
+    /// Result of `eglQuerySurface` with `EGL_PIXEL_ASPECT_RATIO` parameter.
+    pub fn pixel_aspect_ratio(&self) -> Result<Option<f64>> {
+        self.query_scaled(egl::surface_attrib::EGL_PIXEL_ASPECT_RATIO)
+    }
+
+    fn query_scaled(&self, attribute: egl::EGLint) -> Result<Option<f64>> {
+        let value = self.query_attrib(attribute)?;
+        if value == egl::surface_attrib::EGL_UNKNOWN {
+            return Ok(None);
+        }
+        Ok(Some(value as f64 / egl::surface_attrib::EGL_DISPLAY_SCALING as f64))
+    }
+
+    /// `[EGL 1.2]` Set whether the color buffer contents are preserved after
+    /// `swap_buffers`, so that only changed regions need to be redrawn.
+    ///
+    /// Fails with `Error::ConfigNotSwapPreservedCapable` when enabling it, if the
+    /// surface's config does not include `SurfaceType::SWAP_BEHAVIOR_PRESERVED`.
+    ///
+    /// Calls `eglSurfaceAttrib` with `EGL_SWAP_BEHAVIOR` attribute.
+    pub fn set_preserve_buffer(&self, preserve: bool) -> Result<()> {
+        let value = if preserve {
+            if !self.config()?.supports_preserved_swap()? {
+                return Err(Error::ConfigNotSwapPreservedCapable);
+            }
+            egl::surface_attrib::EGL_BUFFER_PRESERVED
+        } else {
+            egl::surface_attrib::EGL_BUFFER_DESTROYED
+        };
+
+        egl::surface_attrib(self.display_handle,
+                            self.handle,
+                            egl::surface_attrib::EGL_SWAP_BEHAVIOR,
+                            value)?;
+        Ok(())
+    }
+
+    /// `[EGL 1.2]` Returns `true` if the color buffer contents are preserved after
+    /// `swap_buffers`.
+    ///
+    /// Result of `eglQuerySurface` with `EGL_SWAP_BEHAVIOR` parameter.
+    pub fn buffer_preserved(&self) -> Result<bool> {
+        let value = self.query_attrib(egl::surface_attrib::EGL_SWAP_BEHAVIOR)?;
+        Ok(SwapBehavior::try_from(value)? == SwapBehavior::Preserved)
+    }
+
+    /// Finds the config this surface was created with, by its `EGL_CONFIG_ID`.
+    fn config(&self) -> Result<FrameBufferConfigRef> {
+        let id = self.query_attrib(egl::config_attrib::EGL_CONFIG_ID)?;
+
+        let mut configs = [ptr::null_mut()];
+        let count = egl::get_filtered_configs(self.display_handle,
+                                              &[egl::config_attrib::EGL_CONFIG_ID,
+                                                id,
+                                                egl::config_attrib::EGL_NONE],
+                                              &mut configs)?;
+        if count < 1 {
+            return Err(Error::UnknownValue(id));
+        }
+        Ok(FrameBufferConfigRef::from_native(self.display_handle, configs[0]))
+    }
+
+    /// `[EGL 1.0]` Post EGL surface color buffer to a native window.
+    ///
+    /// Same as `Display::swap_buffers`, but uses the display this surface was
+    /// created on.
+    ///
+    /// Swapping has no effect on pbuffer and pixmap surfaces.
+    pub fn swap_buffers(&self) -> Result<()> {
+        egl::swap_buffers(self.display_handle, self.handle)?;
+        Ok(())
+    }
+
+    /// `[EGL_KHR_partial_update]` Declare the region of the surface that will be
+    /// rendered in this frame, as `[x, y, width, height]` rectangles with the origin
+    /// at the lower left corner.
+    ///
+    /// Must be called once per frame, after the surface is made current with
+    /// `make_current` and before any rendering to it. Contents outside the region are
+    /// undefined after the next swap, so the driver can skip restoring them.
+    ///
+    /// Fails with `Error::ExtensionNotSupported` if the display does not support
+    /// `EGL_KHR_partial_update`. The extension list is cached, and shared with the
+    /// `Display` that created the surface.
+    pub fn set_damage_region(&self, rects: &[[i32; 4]]) -> Result<()> {
+        if !self.has_extension("EGL_KHR_partial_update")? {
+            return Err(Error::ExtensionNotSupported("EGL_KHR_partial_update"));
+        }
+        egl::set_damage_region_khr(self.display_handle, self.handle, rects)
+    }
+
+    /// Same as `Display::has_extension`, using the cached extension list of the display.
+    fn has_extension(&self, name: &str) -> Result<bool> {
+        let extensions = match self.extensions.get() {
+            Some(extensions) => extensions,
+            None => {
+                let cstr = egl::query_string(self.display_handle,
+                                             egl::query_string::EGL_EXTENSIONS)?;
+                let extensions = cstr.to_str()?;
+                self.extensions.set(Some(extensions));
+                extensions
+            }
+        };
+        Ok(extensions.split_whitespace().any(|e| e == name))
+    }
+
+    /// `[EGL_ANDROID_get_frame_timestamps]` Enable collecting frame timestamps for this
+    /// surface, so that `frame_timestamps` can be used.
+    ///
+    /// Calls `eglSurfaceAttrib` with `EGL_TIMESTAMPS_ANDROID` attribute.
+    #[cfg(android)]
+    pub fn enable_frame_timestamps(&self) -> Result<()> {
+        egl::surface_attrib(self.display_handle,
+                            self.handle,
+                            egl::frame_timestamps::EGL_TIMESTAMPS_ANDROID,
+                            egl::EGL_TRUE as egl::EGLint)?;
+        Ok(())
+    }
+
+    /// `[EGL_ANDROID_get_frame_timestamps]` Returns the identifier of the next frame
+    /// that will be swapped, to be passed to `frame_timestamps` after the swap.
+    #[cfg(android)]
+    pub fn next_frame_id(&self) -> Result<u64> {
+        egl::get_next_frame_id_android(self.display_handle, self.handle)
+    }
+
+    /// `[EGL_ANDROID_get_frame_timestamps]` Returns the composition and presentation
+    /// timestamps of a swapped frame.
+    ///
+    /// Timestamps are only collected after `enable_frame_timestamps`. Fails with
+    /// `Error::FrameTimestampsPending` if some of the timestamps are not known yet;
+    /// query again later, for example after a few more frames.
+    #[cfg(android)]
+    pub fn frame_timestamps(&self, frame_id: u64) -> Result<FrameTimestamps> {
+        use egl::frame_timestamps::*;
+
+        let timestamps = [EGL_REQUESTED_PRESENT_TIME_ANDROID,
+                          EGL_RENDERING_COMPLETE_TIME_ANDROID,
+                          EGL_COMPOSITION_LATCH_TIME_ANDROID,
+                          EGL_FIRST_COMPOSITION_START_TIME_ANDROID,
+                          EGL_LAST_COMPOSITION_START_TIME_ANDROID,
+                          EGL_FIRST_COMPOSITION_GPU_FINISHED_TIME_ANDROID,
+                          EGL_DISPLAY_PRESENT_TIME_ANDROID,
+                          EGL_DEQUEUE_READY_TIME_ANDROID,
+                          EGL_READS_DONE_TIME_ANDROID];
+        let mut values = [0; 9];
+        egl::get_frame_timestamps_android(self.display_handle,
+                                          self.handle,
+                                          frame_id,
+                                          &timestamps,
+                                          &mut values)?;
+
+        if values.contains(&EGL_TIMESTAMP_PENDING_ANDROID) {
+            return Err(Error::FrameTimestampsPending);
+        }
+        let value = |i: usize| if values[i] == EGL_TIMESTAMP_INVALID_ANDROID {
+            None
+        } else {
+            Some(values[i])
+        };
+
+        Ok(FrameTimestamps {
+            requested_present_time: value(0),
+            rendering_complete_time: value(1),
+            composition_latch_time: value(2),
+            first_composition_start_time: value(3),
+            last_composition_start_time: value(4),
+            first_composition_gpu_finished_time: value(5),
+            display_present_time: value(6),
+            dequeue_ready_time: value(7),
+            reads_done_time: value(8),
+        })
+    }
+
+    /// Returns a non-owning copy of this `Surface`.
+    ///
+    /// The copy refers to the same `EGLSurface` handle, but does not destroy it
+    /// when dropped. This allows storing the same surface in several places,
+    /// while exactly one owner is responsible for destroying it.
+    ///
+    /// The copy is not tied to the lifetime of this `Surface`: after the owning
+    /// `Surface` is dropped, the copy refers to a destroyed surface, and calls
+    /// made through it will fail or, if EGL reuses the handle, affect an unrelated
+    /// surface.
+    pub fn borrow(&self) -> Surface {
+        Surface {
+            terminated: true,
+            display_handle: self.display_handle,
+            handle: self.handle,
+            kind: self.kind,
+            display_token: None,
+            extensions: self.extensions.clone(),
+        }
+    }
+
+    /// `[EGL 1.0]` Destroy the surface immediately, and return the result of
+    /// `eglDestroySurface`.
+    ///
+    /// EGL defers destroying a surface that is current to a thread. If this surface is
+    /// the current draw or read surface of the calling thread, the current context is
+    /// first released with `display.make_not_current()`, so the surface is actually
+    /// freed. Surfaces current to other threads are still destroyed only when released.
+    ///
+    /// Fails with `Error::MismatchedDisplay` if `display` is not the display this
+    /// surface was created on; in that case the surface is dropped normally.
+    pub fn destroy_now(self, display: &Display) -> Result<()> {
+        if display.with_handle(|handle| handle) != self.display_handle {
+            return Err(Error::MismatchedDisplay);
+        }
+
+        if self.is_current() {
+            display.make_not_current()?;
+        }
+
+        let display_handle = self.display_handle;
+        let handle = self.into_raw();
+        egl::destroy_surface(display_handle, handle)?;
+        Ok(())
+    }
+
+    /// Returns `true` if this surface is the current draw or read surface of the
+    /// calling thread.
+    fn is_current(&self) -> bool {
+        [egl::EGL_DRAW, egl::EGL_READ]
+            .iter()
+            .any(|&readdraw| egl::get_current_surface(readdraw).ok() == Some(self.handle))
+    }
+
+    /// Disables cleanup of this `Surface` and leaks it, returning a `'static` reference.
+    ///
+    /// Unlike `into_raw`, the wrapper stays usable, for when C code takes ownership of
+    /// the handle but Rust code keeps calling methods on it. The `Surface` is never
+    /// dropped, and `eglDestroySurface` is never called for it; the C side is responsible
+    /// for that. Methods called after the C side destroys the surface will fail or,
+    /// if EGL reuses the handle, affect an unrelated surface.
+    ///
+    /// The leaked `Surface` no longer counts towards the `Display` drop check.
+    ///
+    /// The memory of the wrapper itself is leaked, so use this only for
+    /// long-lived surfaces.
+    pub fn leak(mut self) -> &'static Surface {
+        self.terminated = true;
+        self.display_token = None;
+        Box::leak(Box::new(self))
     }
 
     /// Drops `Surface` without cleaning up any resources.
@@ -78,8 +479,16 @@ impl Surface {
     /// Returns `EGLSurface` handle.
     ///
     /// Alias for `Into<egl::EGLSurface>`.
-    pub fn forget(mut self) -> egl::EGLSurface {
+    pub fn into_raw(mut self) -> egl::EGLSurface {
         self.terminated = true;
         self.handle
     }
+
+    /// Drops `Surface` without cleaning up any resources.
+    ///
+    /// Returns `EGLSurface` handle.
+    #[deprecated(note = "use `Surface::into_raw`")]
+    pub fn forget(self) -> egl::EGLSurface {
+        self.into_raw()
+    }
 }
@@ -0,0 +1,32 @@
+//! Tests that need a working EGL implementation. Run them with
+//! `cargo test --features hardware-tests`.
+
+#![cfg(feature = "hardware-tests")]
+
+extern crate egli;
+
+use egli::Display;
+use egli::egl;
+use egli::error::EglCallError;
+
+#[test]
+fn bind_api_reports_unsupported_api() {
+    let display = Display::from_default_display().expect("failed to get default display");
+    display.initialize().expect("failed to initialize display");
+
+    let client_apis = display.query_client_apis().expect("failed to query client APIs");
+    let unsupported = [("OpenVG", egl::api::EGL_OPENVG_API),
+                       ("OpenGL_ES", egl::api::EGL_OPENGL_ES_API),
+                       ("OpenGL", egl::api::EGL_OPENGL_API)]
+        .iter()
+        .find(|&&(name, _)| !client_apis.split_whitespace().any(|api| api == name))
+        .map(|&(_, api)| api);
+
+    // Nothing to test if the implementation supports all client APIs.
+    let unsupported = match unsupported {
+        Some(api) => api,
+        None => return,
+    };
+
+    assert_eq!(egl::bind_api(unsupported), Err(EglCallError::BindAPIUnsupported));
+}